@@ -8,12 +8,71 @@ mod circuits {
     pub struct VoteStats {
         yes: u64,
         no: u64,
+        abstain: u64, // Counts toward turnout but not toward the majority comparison
+        total: u64,   // Ballots cast, compared against the poll's minimum turnout
     }
 
     /// Tracks encrypted vote tallies for multi-option polls (2-4 options).
+    ///
+    /// The packed layouts below keep `num_options`, `invalid` and `total` at the same positions,
+    /// so every multi-option poll is initialized by `init_multi_option_vote_stats`.
     pub struct MultiOptionVoteStats {
         option_counts: [u64; 4], // Max 4 options, unused slots remain 0
         num_options: u8,          // Actual number of options (2-4)
+        invalid: u64,             // Ballots rejected as out of range
+        total: u64,               // Ballots cast, compared against the poll's minimum turnout
+    }
+
+    /// Tracks encrypted vote counters for multi-option polls with up to 16 options.
+    ///
+    /// Counters are packed four to a `u128` (32 bits each, see `COUNTER_LANES`) so the
+    /// whole state fits in a single callback transaction. The four counter words take the
+    /// place of `MultiOptionVoteStats::option_counts`, so the scalar fields sit where the
+    /// 4-option layout has them. Polls with up to 8 options only use the first two words.
+    pub struct PackedMultiOptionVoteStats16 {
+        packed_counts: [u128; 4], // Option i lives in lane i % 4 of word i / 4
+        num_options: u8,          // Actual number of options (5-16)
+        invalid: u64,             // Ballots rejected as out of range
+        total: u64,               // Ballots cast, compared against the poll's minimum turnout
+    }
+
+    /// Tracks encrypted vote counters for multi-option polls with up to 32 options.
+    ///
+    /// Extends the 16-option layout with four more counter words after its scalar fields.
+    pub struct PackedMultiOptionVoteStats32 {
+        packed_counts: [u128; 4],    // Options 0-15, as in the 16-option layout
+        num_options: u8,             // Actual number of options (17-32)
+        invalid: u64,                // Ballots rejected as out of range
+        total: u64,                  // Ballots cast, compared against the poll's minimum turnout
+        packed_counts_hi: [u128; 4], // Option 16 + i lives in lane i % 4 of word i / 4
+    }
+
+    /// Tracks encrypted Borda scores for ranked-choice polls (3-8 nominees).
+    pub struct RankedVoteStats {
+        scores: [u64; 8], // Max 8 nominees, unused slots remain 0
+        num_options: u8,  // Actual number of nominees (3-8)
+        invalid: u64,     // Malformed ballots that earned no points
+    }
+
+    /// Tracks encrypted score sums for score (range) polls (2-4 options).
+    pub struct ScoreVoteStats {
+        totals: [u64; 4],  // Sum of scores per option, unused slots remain 0
+        voter_count: u64,  // Number of ballots counted
+        num_options: u8,   // Actual number of options (2-4)
+    }
+
+    /// Stores every encrypted instant-runoff ballot of a poll (2-4 options, up to 16 ballots).
+    ///
+    /// Each ballot is a full ranking packed into one byte, two bits per rank:
+    /// `first + 4 * second + 16 * third + 64 * fourth`. Slots beyond the poll's option count
+    /// are stored as option 3, which is then always out of the race. The 16-ballot cap keeps
+    /// the vote and reveal computations, which touch every slot, bounded; see `MAX_IRV_BALLOTS`
+    /// in the program.
+    pub struct IrvBallotBox {
+        ballots: [u8; 16], // Packed rankings, only the first num_ballots entries are used
+        num_ballots: u8,   // Number of valid ballots stored
+        num_options: u8,   // Actual number of options (2-4)
+        invalid: u8,       // Malformed rankings that were not stored
     }
 
     /// Represents a single encrypted vote.
@@ -21,37 +80,124 @@ mod circuits {
         vote: bool,
     }
 
+    /// Represents a single encrypted yes/no/abstain vote (see `CHOICE_*`).
+    pub struct TristateUserVote {
+        choice: u8,
+    }
+
     /// Represents a multi-option vote (user selects one option).
     pub struct MultiOptionUserVote {
         selected_option: u8, // 0-3 (index of selected option)
     }
 
+    /// Represents an approval ballot: any subset of the options (slots beyond num_options are ignored).
+    pub struct ApprovalUserVote {
+        approved: [bool; 4],
+    }
+
+    /// Represents a quadratic ballot: votes per option, costing the sum of their squares in credits.
+    pub struct QuadraticUserVote {
+        votes: [u8; 4],
+    }
+
+    /// Represents a score ballot: a rating of 0-10 for each option.
+    pub struct ScoreUserVote {
+        scores: [u8; 4],
+    }
+
+    /// Represents a ranked ballot (indices of the voter's top three nominees).
+    pub struct RankedUserVote {
+        first: u8,
+        second: u8,
+        third: u8,
+    }
+
+    /// Represents an instant-runoff ballot: option indices from most to least preferred.
+    ///
+    /// Every ballot ranks all four slots; options beyond the poll's option count are
+    /// ignored during counting, so clients place them last.
+    pub struct IrvUserVote {
+        ranks: [u8; 4],
+    }
+
+    /// Increment for each 32-bit lane of a packed counter word: 2^0, 2^32, 2^64, 2^96.
+    ///
+    /// A lane carries into its neighbour once its count reaches 2^32. Packed polls only take
+    /// unweighted single-choice ballots, so each ballot adds at most 1 to one lane and no lane
+    /// can overflow before a poll receives 2^32 ballots.
+    const COUNTER_LANES: [u128; 4] = [
+        1,
+        4_294_967_296,
+        18_446_744_073_709_551_616,
+        79_228_162_514_264_337_593_543_950_336,
+    ];
+
+    /// Borda points awarded for 1st, 2nd and 3rd place.
+    const FIRST_PLACE_POINTS: u64 = 10;
+    const SECOND_PLACE_POINTS: u64 = 6;
+    const THIRD_PLACE_POINTS: u64 = 3;
+
+    /// Choices of a tri-state ballot.
+    const CHOICE_NO: u8 = 0;
+    const CHOICE_YES: u8 = 1;
+    const CHOICE_ABSTAIN: u8 = 2;
+
+    /// Outcome codes revealed by `reveal_result`; mirrored by `PollOutcome` in the program.
+    const OUTCOME_NO: u8 = 0;
+    const OUTCOME_YES: u8 = 1;
+    const OUTCOME_TIE: u8 = 2;
+    const OUTCOME_QUORUM_NOT_MET: u8 = 3;
+
+    /// Margin buckets revealed by `reveal_multi_option_winner`, relative to all valid votes.
+    const MARGIN_TIE: u8 = 0; // No single winner
+    const MARGIN_NARROW: u8 = 1; // Winner leads by less than 10%
+    const MARGIN_CLEAR: u8 = 2; // Winner leads by 10% to less than 25%
+    const MARGIN_LANDSLIDE: u8 = 3; // Winner leads by 25% or more
+
+    /// Cap on each geometric draw of `reveal_multi_option_result_dp`; the program bounds
+    /// epsilon so the delta this truncation adds stays below ~5e-7.
+    const DP_NOISE_CAP: usize = 64;
+
+    /// Highest rating a score ballot can give an option; larger ratings are clamped.
+    const MAX_SCORE: u8 = 10;
+
+    /// Maximum number of ballots an instant-runoff poll can hold.
+    const MAX_IRV_BALLOTS: u8 = 16;
+
     /// Initializes encrypted vote counters for a new poll.
     ///
     /// Creates a VoteStats structure with zero counts for both yes and no votes.
     /// The counters remain encrypted and can only be updated through MPC operations.
     #[instruction]
     pub fn init_vote_stats(mxe: Mxe) -> Enc<Mxe, VoteStats> {
-        let vote_stats = VoteStats { yes: 0, no: 0 };
+        let vote_stats = VoteStats {
+            yes: 0,
+            no: 0,
+            abstain: 0,
+            total: 0,
+        };
         mxe.from_arcis(vote_stats)
     }
 
-    /// Initializes encrypted vote counters for a multi-option poll.
+    /// Initializes encrypted vote counters for a multi-option poll of any capacity.
     ///
-    /// Creates a MultiOptionVoteStats structure with zero counts for all options.
-    /// The num_options parameter (2-4) indicates how many options are actually used.
+    /// Always produces the 32-option layout. Every multi-option layout starts with four counter
+    /// slots followed by `num_options`, `invalid` and `total`, and all counters are zero here, so
+    /// the 4-, 8- and 16-option circuits read a prefix of this state and never touch the rest.
     ///
     /// # Arguments
-    /// * `num_options` - Number of options in the poll (must be 2-4)
+    /// * `num_options` - Number of options in the poll (must be 2-32)
     #[instruction]
     pub fn init_multi_option_vote_stats(
         mxe: Mxe,
-        num_options_ctxt: Enc<Mxe, u8>,
-    ) -> Enc<Mxe, MultiOptionVoteStats> {
-        let num_options = num_options_ctxt.to_arcis();
-        let vote_stats = MultiOptionVoteStats {
-            option_counts: [0, 0, 0, 0],
+        num_options: u8,
+    ) -> Enc<Mxe, PackedMultiOptionVoteStats32> {
+        let vote_stats = PackedMultiOptionVoteStats32 {
+            packed_counts: [0; 4],
             num_options,
+            invalid: 0,
+            total: 0,
+            packed_counts_hi: [0; 4],
         };
         mxe.from_arcis(vote_stats)
     }
@@ -82,6 +228,74 @@ mod circuits {
         } else {
             vote_stats.no += 1;
         }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Processes an encrypted vote weighted by the voter's token balance.
+    ///
+    /// Like `vote`, but adds the public `weight` instead of 1 to the chosen counter, so
+    /// the weight is visible on-chain while the direction stays secret. `total` still
+    /// counts ballots, keeping the poll's minimum turnout a number of voters.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted vote to be counted
+    /// * `vote_stats_ctxt` - Current encrypted vote tallies
+    /// * `weight` - Voter's token balance, read on-chain at vote time
+    ///
+    /// # Returns
+    /// Updated encrypted vote statistics with the weighted vote included
+    #[instruction]
+    pub fn vote_weighted(
+        vote_ctxt: Enc<Shared, UserVote>,
+        vote_stats_ctxt: Enc<Mxe, VoteStats>,
+        weight: u64,
+    ) -> Enc<Mxe, VoteStats> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        if user_vote.vote {
+            vote_stats.yes += weight;
+        } else {
+            vote_stats.no += weight;
+        }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Processes an encrypted yes/no/abstain vote and updates the running tallies.
+    ///
+    /// Abstentions count toward turnout but not toward the yes/no comparison. A choice
+    /// outside `CHOICE_*` is not counted at all, not even toward turnout.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted tri-state vote
+    /// * `vote_stats_ctxt` - Current encrypted vote tallies
+    ///
+    /// # Returns
+    /// Updated encrypted vote statistics with the new vote included
+    #[instruction]
+    pub fn vote_tristate(
+        vote_ctxt: Enc<Shared, TristateUserVote>,
+        vote_stats_ctxt: Enc<Mxe, VoteStats>,
+    ) -> Enc<Mxe, VoteStats> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        if user_vote.choice == CHOICE_YES {
+            vote_stats.yes += 1;
+        }
+        if user_vote.choice == CHOICE_NO {
+            vote_stats.no += 1;
+        }
+        if user_vote.choice == CHOICE_ABSTAIN {
+            vote_stats.abstain += 1;
+        }
+        if user_vote.choice <= CHOICE_ABSTAIN {
+            vote_stats.total += 1;
+        }
 
         vote_stats_ctxt.owner.from_arcis(vote_stats)
     }
@@ -89,8 +303,9 @@ mod circuits {
     /// Processes a multi-option encrypted vote and updates the running tallies.
     ///
     /// Takes an individual vote (selected option index) and increments the corresponding
-    /// counter without revealing which option was selected. The updated vote statistics
-    /// remain encrypted until reveal.
+    /// counter without revealing which option was selected. A selection at or above the
+    /// encrypted `num_options` is counted in the `invalid` counter instead. The updated vote
+    /// statistics remain encrypted until reveal.
     ///
     /// # Arguments
     /// * `vote_ctxt` - The encrypted vote containing selected option index
@@ -106,17 +321,206 @@ mod circuits {
         let user_vote = vote_ctxt.to_arcis();
         let mut vote_stats = vote_stats_ctxt.to_arcis();
 
+        let valid = user_vote.selected_option < vote_stats.num_options;
+
         // Increment the selected option's counter
         // We use a loop to avoid indexing (which is expensive in MPC)
         for i in 0..4 {
-            if user_vote.selected_option == i {
+            if valid && user_vote.selected_option == i {
                 vote_stats.option_counts[i as usize] += 1;
             }
         }
+        if !valid {
+            vote_stats.invalid += 1;
+        }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Processes a single-choice vote weighted by the voter's token balance.
+    ///
+    /// Like `vote_multi_option`, but the selected option's counter grows by the public
+    /// `weight` instead of 1. Invalid ballots and `total` still count ballots.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted selected option index
+    /// * `vote_stats_ctxt` - Current encrypted vote tallies for all options
+    /// * `weight` - Voter's token balance, read on-chain at vote time
+    ///
+    /// # Returns
+    /// Updated encrypted vote statistics with the weighted vote included
+    #[instruction]
+    pub fn vote_multi_option_weighted(
+        vote_ctxt: Enc<Shared, MultiOptionUserVote>,
+        vote_stats_ctxt: Enc<Mxe, MultiOptionVoteStats>,
+        weight: u64,
+    ) -> Enc<Mxe, MultiOptionVoteStats> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        let valid = user_vote.selected_option < vote_stats.num_options;
+
+        for i in 0..4 {
+            if valid && user_vote.selected_option == i {
+                vote_stats.option_counts[i as usize] += weight;
+            }
+        }
+        if !valid {
+            vote_stats.invalid += 1;
+        }
+        vote_stats.total += 1;
 
         vote_stats_ctxt.owner.from_arcis(vote_stats)
     }
 
+    /// Processes an approval ballot for a multi-option poll.
+    ///
+    /// Every approved option's counter is incremented, so a voter can back several
+    /// options at once. A ballot approving a slot beyond the poll's option count is
+    /// malformed: none of its approvals count and the `invalid` counter is incremented.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted approval ballot
+    /// * `vote_stats_ctxt` - Current encrypted vote tallies
+    ///
+    /// # Returns
+    /// Updated encrypted vote statistics with the approvals included
+    #[instruction]
+    pub fn vote_approval(
+        vote_ctxt: Enc<Shared, ApprovalUserVote>,
+        vote_stats_ctxt: Enc<Mxe, MultiOptionVoteStats>,
+    ) -> Enc<Mxe, MultiOptionVoteStats> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        let mut valid = true;
+        for i in 0..4 {
+            if user_vote.approved[i] && (i as u8) >= vote_stats.num_options {
+                valid = false;
+            }
+        }
+
+        for i in 0..4 {
+            if valid && user_vote.approved[i] {
+                vote_stats.option_counts[i] += 1;
+            }
+        }
+        if !valid {
+            vote_stats.invalid += 1;
+        }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Processes a quadratic ballot for a multi-option poll.
+    ///
+    /// Casting `v` votes for an option costs `v * v` credits. A ballot whose total cost
+    /// exceeds `credit_budget`, or that puts votes on a slot beyond the poll's option count,
+    /// is malformed: none of its votes count and the `invalid` counter is incremented.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted vote allocation
+    /// * `vote_stats_ctxt` - Current encrypted vote tallies
+    /// * `credit_budget` - Credits each voter may spend, stored on the poll
+    ///
+    /// # Returns
+    /// Updated encrypted vote statistics with the votes included
+    #[instruction]
+    pub fn vote_quadratic(
+        vote_ctxt: Enc<Shared, QuadraticUserVote>,
+        vote_stats_ctxt: Enc<Mxe, MultiOptionVoteStats>,
+        credit_budget: u64,
+    ) -> Enc<Mxe, MultiOptionVoteStats> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        let mut cost: u64 = 0;
+        let mut in_range = true;
+        for i in 0..4 {
+            let votes = user_vote.votes[i] as u64;
+            cost += votes * votes;
+            if votes > 0 && (i as u8) >= vote_stats.num_options {
+                in_range = false;
+            }
+        }
+        let valid = in_range && cost <= credit_budget;
+
+        for i in 0..4 {
+            if valid {
+                vote_stats.option_counts[i] += user_vote.votes[i] as u64;
+            }
+        }
+        if !valid {
+            vote_stats.invalid += 1;
+        }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Reveals only the winner of a multi-option poll, keeping the counts private.
+    ///
+    /// Determines the option with the most votes, whether that top count is shared,
+    /// and a coarse bucket for the lead over the runner-up (see `MARGIN_*`). On a tie
+    /// the lowest tied index is reported as the winner. If fewer than `min_turnout`
+    /// ballots were cast, only the quorum flag is meaningful and everything else is zeroed.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Encrypted vote tallies to be evaluated
+    /// * `min_turnout` - Minimum number of ballots for the poll to be decided
+    ///
+    /// # Returns
+    /// Tuple of (winning option index, tie flag, margin bucket, quorum met)
+    #[instruction]
+    pub fn reveal_multi_option_winner(
+        vote_stats_ctxt: Enc<Mxe, MultiOptionVoteStats>,
+        min_turnout: u64,
+    ) -> (u8, bool, u8, bool) {
+        let vote_stats = vote_stats_ctxt.to_arcis();
+
+        let mut winner: u8 = 0;
+        let mut top: u64 = 0;
+        let mut runner_up: u64 = 0;
+        let mut total: u64 = 0;
+        for i in 0..4 {
+            let count = vote_stats.option_counts[i];
+            total += count;
+            if count > top {
+                runner_up = top;
+                top = count;
+                winner = i as u8;
+            } else if count > runner_up {
+                runner_up = count;
+            }
+        }
+
+        let quorum_met = vote_stats.total >= min_turnout;
+        if !quorum_met {
+            winner = 0;
+        }
+
+        let margin = top - runner_up;
+        let is_tie = quorum_met && margin == 0;
+        let margin_bucket = if !quorum_met || is_tie {
+            MARGIN_TIE
+        } else if margin * 10 < total {
+            MARGIN_NARROW
+        } else if margin * 4 < total {
+            MARGIN_CLEAR
+        } else {
+            MARGIN_LANDSLIDE
+        };
+
+        (
+            winner.reveal(),
+            is_tie.reveal(),
+            margin_bucket.reveal(),
+            quorum_met.reveal(),
+        )
+    }
+
     /// Reveals the final result of the poll by comparing vote tallies.
     ///
     /// Decrypts the vote counters and determines whether the majority voted yes or no.
@@ -124,14 +528,66 @@ mod circuits {
     ///
     /// # Arguments
     /// * `vote_stats_ctxt` - Encrypted vote tallies to be revealed
+    /// * `min_turnout` - Minimum number of ballots for the poll to be decided
+    ///
+    /// # Returns
+    /// * `OUTCOME_QUORUM_NOT_MET` if fewer than `min_turnout` ballots were cast
+    /// * `OUTCOME_YES` if more people voted yes than no
+    /// * `OUTCOME_NO` if more people voted no than yes
+    /// * `OUTCOME_TIE` on an exact tie
+    #[instruction]
+    pub fn reveal_result(vote_stats_ctxt: Enc<Mxe, VoteStats>, min_turnout: u64) -> u8 {
+        let vote_stats = vote_stats_ctxt.to_arcis();
+        let outcome = if vote_stats.total < min_turnout {
+            OUTCOME_QUORUM_NOT_MET
+        } else if vote_stats.yes > vote_stats.no {
+            OUTCOME_YES
+        } else if vote_stats.no > vote_stats.yes {
+            OUTCOME_NO
+        } else {
+            OUTCOME_TIE
+        };
+        outcome.reveal()
+    }
+
+    /// Reveals whether a poll reached a custom pass threshold (e.g. 2/3 or 60%).
+    ///
+    /// The proposal passes when `yes / (yes + no) >= threshold_numerator / threshold_denominator`,
+    /// evaluated as `yes * den >= num * (yes + no)` so no division is needed. The products are
+    /// taken in u128, since weighted tallies can use the whole u64 range. A poll without any
+    /// yes/no votes fails. Only pass/fail is revealed, never the tallies.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Encrypted vote tallies to be evaluated
+    /// * `min_turnout` - Minimum number of ballots for the poll to be decided
+    /// * `threshold_numerator` - Numerator of the required yes share
+    /// * `threshold_denominator` - Denominator of the required yes share
     ///
     /// # Returns
-    /// * `true` if more people voted yes than no
-    /// * `false` if more people voted no than yes (or tie)
+    /// * `OUTCOME_QUORUM_NOT_MET` if fewer than `min_turnout` ballots were cast
+    /// * `OUTCOME_YES` if the proposal passed
+    /// * `OUTCOME_NO` if it failed
     #[instruction]
-    pub fn reveal_result(vote_stats_ctxt: Enc<Mxe, VoteStats>) -> bool {
+    pub fn reveal_threshold_result(
+        vote_stats_ctxt: Enc<Mxe, VoteStats>,
+        min_turnout: u64,
+        threshold_numerator: u64,
+        threshold_denominator: u64,
+    ) -> u8 {
         let vote_stats = vote_stats_ctxt.to_arcis();
-        (vote_stats.yes > vote_stats.no).reveal()
+        let decisive = vote_stats.yes as u128 + vote_stats.no as u128;
+        let passed = decisive > 0
+            && vote_stats.yes as u128 * threshold_denominator as u128
+                >= threshold_numerator as u128 * decisive;
+
+        let outcome = if vote_stats.total < min_turnout {
+            OUTCOME_QUORUM_NOT_MET
+        } else if passed {
+            OUTCOME_YES
+        } else {
+            OUTCOME_NO
+        };
+        outcome.reveal()
     }
 
     /// Reveals the vote counts for a multi-option poll.
@@ -141,15 +597,33 @@ mod circuits {
     ///
     /// # Arguments
     /// * `vote_stats_ctxt` - Encrypted vote tallies to be revealed
+    /// * `min_turnout` - Minimum number of ballots for the counts to be disclosed
+    /// * `min_ballots` - k-anonymity threshold: fewer ballots never disclose the counts
     ///
     /// # Returns
-    /// Array of vote counts [count1, count2, count3, count4]
-    /// Unused option slots will have count of 0
+    /// Tuple of (vote counts [count1, count2, count3, count4], number of invalid ballots,
+    /// quorum met, sufficient participation). Unused option slots will have count of 0;
+    /// unless both flags are set all counts are reported as 0
     #[instruction]
     pub fn reveal_multi_option_result(
         vote_stats_ctxt: Enc<Mxe, MultiOptionVoteStats>,
-    ) -> [u64; 4] {
-        let vote_stats = vote_stats_ctxt.to_arcis();
+        min_turnout: u64,
+        min_ballots: u64,
+    ) -> ([u64; 4], u64, bool, bool) {
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+        let quorum_met = vote_stats.total >= min_turnout;
+        let sufficient_participation = vote_stats.total >= min_ballots;
+        let disclose_counts = quorum_met & sufficient_participation;
+
+        // Hide the counts of polls that did not reach the quorum or the k-anonymity threshold
+        for i in 0..4 {
+            if !disclose_counts {
+                vote_stats.option_counts[i] = 0;
+            }
+        }
+        if !disclose_counts {
+            vote_stats.invalid = 0;
+        }
         
         // Reveal all counts
         let counts = [
@@ -159,6 +633,618 @@ mod circuits {
             vote_stats.option_counts[3].reveal(),
         ];
         
-        counts
+        (counts, vote_stats.invalid.reveal(), quorum_met.reveal(), sufficient_participation.reveal())
+    }
+
+    /// Reveals differentially private vote counts for a multi-option poll.
+    ///
+    /// Each count receives two-sided geometric (discrete Laplace) noise drawn from MPC
+    /// randomness: the difference of two geometric draws that continue with probability
+    /// `continue_probability / 2^16`, i.e. e^-epsilon as computed by the program. Each draw is
+    /// truncated at `DP_NOISE_CAP`, which is not post-processing: it changes the noise by at
+    /// most 2 * e^(-epsilon * DP_NOISE_CAP) in total variation, so each count is
+    /// (epsilon, delta)-DP with delta <= 2 * e^(-64 * epsilon) * (1 + e^epsilon) rather than
+    /// purely epsilon-DP. Clamping the noisy counts at 0 is post-processing and costs nothing.
+    /// Unused option slots are reported as 0.
+    ///
+    /// The quorum and k-anonymity gates apply as in `reveal_multi_option_result`: unless both
+    /// pass, every count is reported as 0. The two flags are computed from the exact ballot
+    /// count and revealed without noise.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Encrypted vote tallies to be revealed
+    /// * `continue_probability` - e^-epsilon as a 16-bit fixed-point fraction
+    /// * `min_turnout` - Minimum number of ballots for the counts to be disclosed
+    /// * `min_ballots` - k-anonymity threshold: fewer ballots never disclose the counts
+    ///
+    /// # Returns
+    /// Tuple of (noisy vote counts [count1, count2, count3, count4], quorum met,
+    /// sufficient participation)
+    #[instruction]
+    pub fn reveal_multi_option_result_dp(
+        vote_stats_ctxt: Enc<Mxe, MultiOptionVoteStats>,
+        continue_probability: u128,
+        min_turnout: u64,
+        min_ballots: u64,
+    ) -> ([u64; 4], bool, bool) {
+        let vote_stats = vote_stats_ctxt.to_arcis();
+        let quorum_met = vote_stats.total >= min_turnout;
+        let sufficient_participation = vote_stats.total >= min_ballots;
+        let disclose_counts = quorum_met & sufficient_participation;
+
+        let mut noisy_counts = [0u64; 4];
+        for i in 0..4 {
+            let mut positive: u64 = 0;
+            let mut negative: u64 = 0;
+            let mut positive_running = true;
+            let mut negative_running = true;
+            for _ in 0..DP_NOISE_CAP {
+                positive_running = positive_running
+                    & (ArcisRNG::gen_integer_from_width(16) < continue_probability);
+                negative_running = negative_running
+                    & (ArcisRNG::gen_integer_from_width(16) < continue_probability);
+                if positive_running {
+                    positive += 1;
+                }
+                if negative_running {
+                    negative += 1;
+                }
+            }
+
+            // Clamp at 0 without underflowing: max(count + positive, negative) - negative
+            let shifted = vote_stats.option_counts[i] + positive;
+            let floored = if shifted > negative { shifted } else { negative };
+            if disclose_counts && (i as u8) < vote_stats.num_options {
+                noisy_counts[i] = floored - negative;
+            }
+        }
+
+        let counts = [
+            noisy_counts[0].reveal(),
+            noisy_counts[1].reveal(),
+            noisy_counts[2].reveal(),
+            noisy_counts[3].reveal(),
+        ];
+
+        (counts, quorum_met.reveal(), sufficient_participation.reveal())
+    }
+
+    /// Initializes encrypted Borda scores for a ranked-choice poll.
+    ///
+    /// # Arguments
+    /// * `num_options` - Number of nominees in the poll (must be 3-8)
+    #[instruction]
+    pub fn init_ranked_vote_stats(mxe: Mxe, num_options: u8) -> Enc<Mxe, RankedVoteStats> {
+        let vote_stats = RankedVoteStats {
+            scores: [0; 8],
+            num_options,
+            invalid: 0,
+        };
+        mxe.from_arcis(vote_stats)
+    }
+
+    /// Processes a ranked ballot and adds its Borda points to the running scores.
+    ///
+    /// The 1st, 2nd and 3rd choices earn 10, 6 and 3 points respectively. The ballot is
+    /// validated inside MPC: if any rank is out of range or two ranks name the same nominee,
+    /// no points are awarded and the `invalid` counter is incremented instead. Neither the ranks
+    /// nor the validity of an individual ballot are revealed.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted ranked ballot
+    /// * `vote_stats_ctxt` - Current encrypted Borda scores
+    ///
+    /// # Returns
+    /// Updated encrypted Borda scores with the new ballot included
+    #[instruction]
+    pub fn vote_ranked(
+        vote_ctxt: Enc<Shared, RankedUserVote>,
+        vote_stats_ctxt: Enc<Mxe, RankedVoteStats>,
+    ) -> Enc<Mxe, RankedVoteStats> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        let valid = user_vote.first < vote_stats.num_options
+            && user_vote.second < vote_stats.num_options
+            && user_vote.third < vote_stats.num_options
+            && user_vote.first != user_vote.second
+            && user_vote.first != user_vote.third
+            && user_vote.second != user_vote.third;
+
+        // Loop over every slot rather than indexing by the secret ranks
+        for i in 0..8 {
+            if valid && user_vote.first == i {
+                vote_stats.scores[i as usize] += FIRST_PLACE_POINTS;
+            }
+            if valid && user_vote.second == i {
+                vote_stats.scores[i as usize] += SECOND_PLACE_POINTS;
+            }
+            if valid && user_vote.third == i {
+                vote_stats.scores[i as usize] += THIRD_PLACE_POINTS;
+            }
+        }
+        if !valid {
+            vote_stats.invalid += 1;
+        }
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Reveals the Borda scores of a ranked-choice poll.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Encrypted Borda scores to be revealed
+    ///
+    /// # Returns
+    /// Tuple of (score per nominee, unused slots being 0; number of malformed ballots)
+    #[instruction]
+    pub fn reveal_ranked_result(vote_stats_ctxt: Enc<Mxe, RankedVoteStats>) -> ([u64; 8], u64) {
+        let vote_stats = vote_stats_ctxt.to_arcis();
+
+        let mut scores = [0u64; 8];
+        for i in 0..8 {
+            scores[i] = vote_stats.scores[i].reveal();
+        }
+
+        (scores, vote_stats.invalid.reveal())
+    }
+
+    /// Initializes an empty encrypted ballot box for an instant-runoff poll.
+    ///
+    /// # Arguments
+    /// * `num_options` - Number of options in the poll (must be 2-4)
+    #[instruction]
+    pub fn init_irv_ballot_box(mxe: Mxe, num_options: u8) -> Enc<Mxe, IrvBallotBox> {
+        let ballot_box = IrvBallotBox {
+            ballots: [0; 16],
+            num_ballots: 0,
+            num_options,
+            invalid: 0,
+        };
+        mxe.from_arcis(ballot_box)
+    }
+
+    /// Stores an encrypted instant-runoff ballot in the ballot box.
+    ///
+    /// The ranking is kept in full so elimination rounds can be run at reveal time. Only the
+    /// first `num_options` slots are read, and they must rank every option exactly once;
+    /// malformed ballots are not stored but counted in `invalid`, revealed with the result.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted ranking
+    /// * `ballot_box_ctxt` - Current encrypted ballot box
+    ///
+    /// # Returns
+    /// Updated encrypted ballot box with the new ballot included
+    #[instruction]
+    pub fn vote_irv(
+        vote_ctxt: Enc<Shared, IrvUserVote>,
+        ballot_box_ctxt: Enc<Mxe, IrvBallotBox>,
+    ) -> Enc<Mxe, IrvBallotBox> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut ballot_box = ballot_box_ctxt.to_arcis();
+        let ranks = user_vote.ranks;
+
+        let mut well_formed = true;
+        let mut stored = ranks;
+        for i in 0..4 {
+            let used = (i as u8) < ballot_box.num_options;
+            well_formed = well_formed && (!used || ranks[i] < ballot_box.num_options);
+            for j in 0..4 {
+                // A used slot j > i implies slot i is used too
+                if j > i {
+                    let both_used = (j as u8) < ballot_box.num_options;
+                    well_formed = well_formed && (!both_used || ranks[i] != ranks[j]);
+                }
+            }
+            if !used {
+                stored[i] = 3;
+            }
+        }
+        let valid = well_formed && ballot_box.num_ballots < MAX_IRV_BALLOTS;
+
+        let packed = stored[0] + stored[1] * 4 + stored[2] * 16 + stored[3] * 64;
+
+        // Write into the next free slot without indexing by the secret ballot count
+        for i in 0..16 {
+            if valid && ballot_box.num_ballots == i {
+                ballot_box.ballots[i as usize] = packed;
+            }
+        }
+        if valid {
+            ballot_box.num_ballots += 1;
+        }
+        if !well_formed {
+            ballot_box.invalid += 1;
+        }
+
+        ballot_box_ctxt.owner.from_arcis(ballot_box)
+    }
+
+    /// Runs instant-runoff elimination rounds and reveals only the winner.
+    ///
+    /// Each round counts every ballot for its highest-ranked option still in the race.
+    /// An option holding a strict majority wins; otherwise the option with the fewest
+    /// votes is eliminated (ties eliminate the higher index) and the next round begins.
+    /// Individual rankings and per-round counts never leave MPC.
+    ///
+    /// # Arguments
+    /// * `ballot_box_ctxt` - Encrypted ballot box to be counted
+    ///
+    /// # Returns
+    /// Tuple of (winning option index, number of rounds needed, number of malformed ballots);
+    /// 0 rounds means no valid ballots were cast
+    #[instruction]
+    pub fn reveal_irv_result(ballot_box_ctxt: Enc<Mxe, IrvBallotBox>) -> (u8, u8, u8) {
+        let ballot_box = ballot_box_ctxt.to_arcis();
+
+        let mut eliminated = [false; 4];
+        for j in 0..4 {
+            eliminated[j] = (j as u8) >= ballot_box.num_options;
+        }
+
+        let mut winner: u8 = 0;
+        let mut rounds: u8 = 0;
+        let mut decided = false;
+
+        // With at most 4 options a winner is always found within 4 rounds
+        for round in 0..4 {
+            let mut counts = [0u8; 4];
+
+            for b in 0..16 {
+                let packed = ballot_box.ballots[b];
+                let ranks = [
+                    packed - (packed >> 2) * 4,
+                    (packed >> 2) - (packed >> 4) * 4,
+                    (packed >> 4) - (packed >> 6) * 4,
+                    packed >> 6,
+                ];
+
+                // Walk the ranking from last to first so the most preferred survivor wins
+                let mut top: u8 = 4;
+                for k in 0..4 {
+                    let choice = ranks[3 - k];
+                    let mut choice_eliminated = false;
+                    for j in 0..4 {
+                        if choice == j as u8 && eliminated[j] {
+                            choice_eliminated = true;
+                        }
+                    }
+                    if !choice_eliminated {
+                        top = choice;
+                    }
+                }
+
+                for j in 0..4 {
+                    if (b as u8) < ballot_box.num_ballots && top == j as u8 {
+                        counts[j] += 1;
+                    }
+                }
+            }
+
+            for j in 0..4 {
+                if !decided && !eliminated[j] && counts[j] * 2 > ballot_box.num_ballots {
+                    winner = j as u8;
+                    rounds = round + 1;
+                    decided = true;
+                }
+            }
+
+            let mut loser: u8 = 0;
+            let mut fewest: u8 = u8::MAX;
+            for j in 0..4 {
+                if !eliminated[j] && counts[j] <= fewest {
+                    fewest = counts[j];
+                    loser = j as u8;
+                }
+            }
+            for j in 0..4 {
+                if !decided && loser == j as u8 {
+                    eliminated[j] = true;
+                }
+            }
+        }
+
+        (winner.reveal(), rounds.reveal(), ballot_box.invalid.reveal())
+    }
+
+    /// Initializes encrypted score sums for a score poll.
+    ///
+    /// # Arguments
+    /// * `num_options` - Number of options in the poll (must be 2-4)
+    #[instruction]
+    pub fn init_score_vote_stats(mxe: Mxe, num_options: u8) -> Enc<Mxe, ScoreVoteStats> {
+        let vote_stats = ScoreVoteStats {
+            totals: [0; 4],
+            voter_count: 0,
+            num_options,
+        };
+        mxe.from_arcis(vote_stats)
+    }
+
+    /// Processes a score ballot and adds each rating to the option's running sum.
+    ///
+    /// Ratings above `MAX_SCORE` are clamped inside MPC so a malicious client cannot
+    /// skew the totals, and ratings for slots beyond the poll's option count are ignored.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted score ballot
+    /// * `vote_stats_ctxt` - Current encrypted score sums
+    ///
+    /// # Returns
+    /// Updated encrypted score sums with the new ballot included
+    #[instruction]
+    pub fn vote_score(
+        vote_ctxt: Enc<Shared, ScoreUserVote>,
+        vote_stats_ctxt: Enc<Mxe, ScoreVoteStats>,
+    ) -> Enc<Mxe, ScoreVoteStats> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+
+        for i in 0..4 {
+            let score = if user_vote.scores[i] > MAX_SCORE {
+                MAX_SCORE
+            } else {
+                user_vote.scores[i]
+            };
+            if (i as u8) < vote_stats.num_options {
+                vote_stats.totals[i] += score as u64;
+            }
+        }
+        vote_stats.voter_count += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Reveals the score sums and ballot count of a score poll.
+    ///
+    /// The frontend derives each option's average as `total / voter_count`.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Encrypted score sums to be revealed
+    ///
+    /// # Returns
+    /// Tuple of (sum of scores per option, number of ballots counted)
+    #[instruction]
+    pub fn reveal_score_result(vote_stats_ctxt: Enc<Mxe, ScoreVoteStats>) -> ([u64; 4], u64) {
+        let vote_stats = vote_stats_ctxt.to_arcis();
+
+        let mut totals = [0u64; 4];
+        for i in 0..4 {
+            totals[i] = vote_stats.totals[i].reveal();
+        }
+
+        (totals, vote_stats.voter_count.reveal())
+    }
+
+    /// Processes an encrypted single-choice vote for a multi-option poll with up to 8 options.
+    ///
+    /// Uses the 16-option layout but only touches its first two counter words. Out-of-range
+    /// selections are counted as invalid so they cannot inflate unused counters.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted selected option index
+    /// * `vote_stats_ctxt` - Current packed encrypted vote counters
+    ///
+    /// # Returns
+    /// Updated packed encrypted vote counters with the new vote included
+    #[instruction]
+    pub fn vote_multi_option_8(
+        vote_ctxt: Enc<Shared, MultiOptionUserVote>,
+        vote_stats_ctxt: Enc<Mxe, PackedMultiOptionVoteStats16>,
+    ) -> Enc<Mxe, PackedMultiOptionVoteStats16> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+        let in_range = user_vote.selected_option < vote_stats.num_options;
+
+        for word in 0..2 {
+            for lane in 0..4 {
+                if in_range && user_vote.selected_option == (word * 4 + lane) as u8 {
+                    vote_stats.packed_counts[word] += COUNTER_LANES[lane];
+                }
+            }
+        }
+        if !in_range {
+            vote_stats.invalid += 1;
+        }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Reveals the packed vote counters of a multi-option poll with up to 8 options.
+    ///
+    /// The program unpacks the 32-bit lanes into per-option counts.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Packed encrypted vote counters to be revealed
+    /// * `min_turnout` - Minimum number of ballots for the counts to be disclosed
+    /// * `min_ballots` - k-anonymity threshold: fewer ballots never disclose the counts
+    ///
+    /// # Returns
+    /// Tuple of (packed counter words, number of invalid ballots, quorum met,
+    /// sufficient participation); unless both flags are set all counters are reported as 0
+    #[instruction]
+    pub fn reveal_multi_option_8_result(
+        vote_stats_ctxt: Enc<Mxe, PackedMultiOptionVoteStats16>,
+        min_turnout: u64,
+        min_ballots: u64,
+    ) -> ([u128; 2], u64, bool, bool) {
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+        let quorum_met = vote_stats.total >= min_turnout;
+        let sufficient_participation = vote_stats.total >= min_ballots;
+        let disclose_counts = quorum_met & sufficient_participation;
+
+        // Hide the counts of polls that did not reach the quorum or the k-anonymity threshold
+        for i in 0..2 {
+            if !disclose_counts {
+                vote_stats.packed_counts[i] = 0;
+            }
+        }
+        if !disclose_counts {
+            vote_stats.invalid = 0;
+        }
+
+        let mut packed_counts = [0u128; 2];
+        for i in 0..2 {
+            packed_counts[i] = vote_stats.packed_counts[i].reveal();
+        }
+
+        (packed_counts, vote_stats.invalid.reveal(), quorum_met.reveal(), sufficient_participation.reveal())
+    }
+
+    /// Processes an encrypted single-choice vote for a multi-option poll with up to 16 options.
+    ///
+    /// Out-of-range selections are counted as invalid so they cannot inflate unused counters.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted selected option index
+    /// * `vote_stats_ctxt` - Current packed encrypted vote counters
+    ///
+    /// # Returns
+    /// Updated packed encrypted vote counters with the new vote included
+    #[instruction]
+    pub fn vote_multi_option_16(
+        vote_ctxt: Enc<Shared, MultiOptionUserVote>,
+        vote_stats_ctxt: Enc<Mxe, PackedMultiOptionVoteStats16>,
+    ) -> Enc<Mxe, PackedMultiOptionVoteStats16> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+        let in_range = user_vote.selected_option < vote_stats.num_options;
+
+        for word in 0..4 {
+            for lane in 0..4 {
+                if in_range && user_vote.selected_option == (word * 4 + lane) as u8 {
+                    vote_stats.packed_counts[word] += COUNTER_LANES[lane];
+                }
+            }
+        }
+        if !in_range {
+            vote_stats.invalid += 1;
+        }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Reveals the packed vote counters of a multi-option poll with up to 16 options.
+    ///
+    /// The program unpacks the 32-bit lanes into per-option counts.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Packed encrypted vote counters to be revealed
+    /// * `min_turnout` - Minimum number of ballots for the counts to be disclosed
+    /// * `min_ballots` - k-anonymity threshold: fewer ballots never disclose the counts
+    ///
+    /// # Returns
+    /// Tuple of (packed counter words, number of invalid ballots, quorum met,
+    /// sufficient participation); unless both flags are set all counters are reported as 0
+    #[instruction]
+    pub fn reveal_multi_option_16_result(
+        vote_stats_ctxt: Enc<Mxe, PackedMultiOptionVoteStats16>,
+        min_turnout: u64,
+        min_ballots: u64,
+    ) -> ([u128; 4], u64, bool, bool) {
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+        let quorum_met = vote_stats.total >= min_turnout;
+        let sufficient_participation = vote_stats.total >= min_ballots;
+        let disclose_counts = quorum_met & sufficient_participation;
+
+        // Hide the counts of polls that did not reach the quorum or the k-anonymity threshold
+        for i in 0..4 {
+            if !disclose_counts {
+                vote_stats.packed_counts[i] = 0;
+            }
+        }
+        if !disclose_counts {
+            vote_stats.invalid = 0;
+        }
+
+        let mut packed_counts = [0u128; 4];
+        for i in 0..4 {
+            packed_counts[i] = vote_stats.packed_counts[i].reveal();
+        }
+
+        (packed_counts, vote_stats.invalid.reveal(), quorum_met.reveal(), sufficient_participation.reveal())
+    }
+
+    /// Processes an encrypted single-choice vote for a multi-option poll with up to 32 options.
+    ///
+    /// Out-of-range selections are counted as invalid so they cannot inflate unused counters.
+    ///
+    /// # Arguments
+    /// * `vote_ctxt` - The encrypted selected option index
+    /// * `vote_stats_ctxt` - Current packed encrypted vote counters
+    ///
+    /// # Returns
+    /// Updated packed encrypted vote counters with the new vote included
+    #[instruction]
+    pub fn vote_multi_option_32(
+        vote_ctxt: Enc<Shared, MultiOptionUserVote>,
+        vote_stats_ctxt: Enc<Mxe, PackedMultiOptionVoteStats32>,
+    ) -> Enc<Mxe, PackedMultiOptionVoteStats32> {
+        let user_vote = vote_ctxt.to_arcis();
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+        let in_range = user_vote.selected_option < vote_stats.num_options;
+
+        for word in 0..4 {
+            for lane in 0..4 {
+                if in_range && user_vote.selected_option == (word * 4 + lane) as u8 {
+                    vote_stats.packed_counts[word] += COUNTER_LANES[lane];
+                }
+                if in_range && user_vote.selected_option == (16 + word * 4 + lane) as u8 {
+                    vote_stats.packed_counts_hi[word] += COUNTER_LANES[lane];
+                }
+            }
+        }
+        if !in_range {
+            vote_stats.invalid += 1;
+        }
+        vote_stats.total += 1;
+
+        vote_stats_ctxt.owner.from_arcis(vote_stats)
+    }
+
+    /// Reveals the packed vote counters of a multi-option poll with up to 32 options.
+    ///
+    /// The program unpacks the 32-bit lanes into per-option counts.
+    ///
+    /// # Arguments
+    /// * `vote_stats_ctxt` - Packed encrypted vote counters to be revealed
+    /// * `min_turnout` - Minimum number of ballots for the counts to be disclosed
+    /// * `min_ballots` - k-anonymity threshold: fewer ballots never disclose the counts
+    ///
+    /// # Returns
+    /// Tuple of (packed counter words, number of invalid ballots, quorum met,
+    /// sufficient participation); unless both flags are set all counters are reported as 0
+    #[instruction]
+    pub fn reveal_multi_option_32_result(
+        vote_stats_ctxt: Enc<Mxe, PackedMultiOptionVoteStats32>,
+        min_turnout: u64,
+        min_ballots: u64,
+    ) -> ([u128; 8], u64, bool, bool) {
+        let mut vote_stats = vote_stats_ctxt.to_arcis();
+        let quorum_met = vote_stats.total >= min_turnout;
+        let sufficient_participation = vote_stats.total >= min_ballots;
+        let disclose_counts = quorum_met & sufficient_participation;
+
+        // Hide the counts of polls that did not reach the quorum or the k-anonymity threshold
+        for i in 0..4 {
+            if !disclose_counts {
+                vote_stats.packed_counts[i] = 0;
+                vote_stats.packed_counts_hi[i] = 0;
+            }
+        }
+        if !disclose_counts {
+            vote_stats.invalid = 0;
+        }
+
+        let mut packed_counts = [0u128; 8];
+        for i in 0..4 {
+            packed_counts[i] = vote_stats.packed_counts[i].reveal();
+            packed_counts[i + 4] = vote_stats.packed_counts_hi[i].reveal();
+        }
+
+        (packed_counts, vote_stats.invalid.reveal(), quorum_met.reveal(), sufficient_participation.reveal())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,161 @@
+//! Merkle allowlists restricting which wallets may vote on a poll.
+//!
+//! Leaves are `sha256(0x00 || wallet)` and inner nodes `sha256(0x01 || min(a, b) || max(a, b))`,
+//! so proofs carry no left/right flags. The `allowlist-builder` feature adds `AllowlistTree`,
+//! which builds roots and proofs off-chain with exactly the hashing verified on-chain.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hashes a wallet into an allowlist leaf.
+pub fn leaf_hash(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, wallet.as_ref()]).to_bytes()
+}
+
+/// Hashes two sibling nodes into their parent, independent of their order.
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, lo, hi]).to_bytes()
+}
+
+/// Checks that `proof` links `wallet`'s leaf to `root`.
+pub fn verify(root: &[u8; 32], wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf_hash(wallet), |node, sibling| node_hash(&node, sibling));
+    computed == *root
+}
+
+/// Merkle tree over a set of wallets, for computing a poll's allowlist root and voter proofs.
+///
+/// Leaves are sorted and deduplicated, so the same set of wallets always yields the same root
+/// regardless of input order. A node without a sibling is carried up to the next layer unchanged.
+#[cfg(feature = "allowlist-builder")]
+pub struct AllowlistTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+#[cfg(feature = "allowlist-builder")]
+impl AllowlistTree {
+    /// Builds the tree. Panics if `wallets` is empty.
+    pub fn new(wallets: &[Pubkey]) -> Self {
+        assert!(!wallets.is_empty(), "an allowlist needs at least one wallet");
+
+        let mut leaves: Vec<[u8; 32]> = wallets.iter().map(leaf_hash).collect();
+        leaves.sort_unstable();
+        leaves.dedup();
+
+        let mut layers = vec![leaves];
+        while layers.last().map_or(0, Vec::len) > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node_hash(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self { layers }
+    }
+
+    /// Root to store on the poll at creation.
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// Proof to pass when `wallet` votes, or `None` if it is not on the allowlist.
+    pub fn proof(&self, wallet: &Pubkey) -> Option<Vec<[u8; 32]>> {
+        let mut index = self.layers[0].binary_search(&leaf_hash(wallet)).ok()?;
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(all(test, feature = "allowlist-builder"))]
+mod tests {
+    use super::*;
+
+    fn random_wallets(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn every_member_proof_verifies() {
+        for n in [1, 2, 4, 8] {
+            let wallets = random_wallets(n);
+            let tree = AllowlistTree::new(&wallets);
+            for wallet in &wallets {
+                let proof = tree.proof(wallet).unwrap();
+                assert!(verify(&tree.root(), wallet, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn odd_leaf_counts_carry_the_last_node_up() {
+        for n in [3, 5, 7] {
+            let wallets = random_wallets(n);
+            let tree = AllowlistTree::new(&wallets);
+            for wallet in &wallets {
+                let proof = tree.proof(wallet).unwrap();
+                assert!(verify(&tree.root(), wallet, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn non_member_is_rejected() {
+        let wallets = random_wallets(5);
+        let tree = AllowlistTree::new(&wallets);
+        let outsider = Pubkey::new_unique();
+
+        assert!(tree.proof(&outsider).is_none());
+        for wallet in &wallets {
+            let proof = tree.proof(wallet).unwrap();
+            assert!(!verify(&tree.root(), &outsider, &proof));
+        }
+    }
+
+    #[test]
+    fn wrong_proof_is_rejected() {
+        let wallets = random_wallets(4);
+        let tree = AllowlistTree::new(&wallets);
+        let proof = tree.proof(&wallets[0]).unwrap();
+
+        let mut tampered = proof.clone();
+        tampered[0][0] ^= 1;
+        assert!(!verify(&tree.root(), &wallets[0], &tampered));
+        let truncated = &proof[..proof.len() - 1];
+        assert!(!verify(&tree.root(), &wallets[0], truncated));
+
+        let other_root = AllowlistTree::new(&random_wallets(4)).root();
+        assert!(!verify(&other_root, &wallets[0], &proof));
+    }
+
+    #[test]
+    fn root_does_not_depend_on_input_order_or_duplicates() {
+        let wallets = random_wallets(6);
+        let mut shuffled = wallets.clone();
+        shuffled.reverse();
+        shuffled.swap(1, 4);
+        shuffled.push(wallets[2]);
+
+        assert_eq!(
+            AllowlistTree::new(&wallets).root(),
+            AllowlistTree::new(&shuffled).root()
+        );
+    }
+}
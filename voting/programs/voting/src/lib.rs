@@ -1,13 +1,70 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::mpl_token_metadata::types::Collection;
+use anchor_spl::metadata::MetadataAccount;
+use anchor_spl::token::TokenAccount;
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::{CallbackAccount, CircuitSource, OffChainCircuitSource};
 
+pub mod allowlist;
+
 const COMP_DEF_OFFSET_INIT_VOTE_STATS: u32 = comp_def_offset("init_vote_stats");
 const COMP_DEF_OFFSET_VOTE: u32 = comp_def_offset("vote");
+const COMP_DEF_OFFSET_VOTE_TRISTATE: u32 = comp_def_offset("vote_tristate");
+const COMP_DEF_OFFSET_VOTE_WEIGHTED: u32 = comp_def_offset("vote_weighted");
 const COMP_DEF_OFFSET_REVEAL: u32 = comp_def_offset("reveal_result");
+const COMP_DEF_OFFSET_REVEAL_THRESHOLD: u32 = comp_def_offset("reveal_threshold_result");
 const COMP_DEF_OFFSET_INIT_MULTI_OPTION_VOTE_STATS: u32 = comp_def_offset("init_multi_option_vote_stats");
 const COMP_DEF_OFFSET_VOTE_MULTI_OPTION: u32 = comp_def_offset("vote_multi_option");
 const COMP_DEF_OFFSET_REVEAL_MULTI_OPTION: u32 = comp_def_offset("reveal_multi_option_result");
+const COMP_DEF_OFFSET_VOTE_APPROVAL: u32 = comp_def_offset("vote_approval");
+const COMP_DEF_OFFSET_VOTE_QUADRATIC: u32 = comp_def_offset("vote_quadratic");
+const COMP_DEF_OFFSET_VOTE_MULTI_OPTION_WEIGHTED: u32 = comp_def_offset("vote_multi_option_weighted");
+const COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_WINNER: u32 = comp_def_offset("reveal_multi_option_winner");
+const COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_DP: u32 = comp_def_offset("reveal_multi_option_result_dp");
+const COMP_DEF_OFFSET_VOTE_MULTI_OPTION_8: u32 = comp_def_offset("vote_multi_option_8");
+const COMP_DEF_OFFSET_VOTE_MULTI_OPTION_16: u32 = comp_def_offset("vote_multi_option_16");
+const COMP_DEF_OFFSET_VOTE_MULTI_OPTION_32: u32 = comp_def_offset("vote_multi_option_32");
+const COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_8: u32 = comp_def_offset("reveal_multi_option_8_result");
+const COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_16: u32 = comp_def_offset("reveal_multi_option_16_result");
+const COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_32: u32 = comp_def_offset("reveal_multi_option_32_result");
+const COMP_DEF_OFFSET_INIT_RANKED_VOTE_STATS: u32 = comp_def_offset("init_ranked_vote_stats");
+const COMP_DEF_OFFSET_VOTE_RANKED: u32 = comp_def_offset("vote_ranked");
+const COMP_DEF_OFFSET_REVEAL_RANKED: u32 = comp_def_offset("reveal_ranked_result");
+const COMP_DEF_OFFSET_INIT_IRV_BALLOT_BOX: u32 = comp_def_offset("init_irv_ballot_box");
+const COMP_DEF_OFFSET_VOTE_IRV: u32 = comp_def_offset("vote_irv");
+const COMP_DEF_OFFSET_REVEAL_IRV: u32 = comp_def_offset("reveal_irv_result");
+const COMP_DEF_OFFSET_INIT_SCORE_VOTE_STATS: u32 = comp_def_offset("init_score_vote_stats");
+const COMP_DEF_OFFSET_VOTE_SCORE: u32 = comp_def_offset("vote_score");
+const COMP_DEF_OFFSET_REVEAL_SCORE: u32 = comp_def_offset("reveal_score_result");
+
+/// Largest accepted pass threshold denominator. The circuit compares in u128, so this is an input
+/// sanity bound (finer than 0.01% shares are not meaningful), not an overflow guard.
+const MAX_THRESHOLD_DENOMINATOR: u64 = 10_000;
+
+/// Maximum number of ballots an instant-runoff poll can hold; must match the `vote_irv` circuit.
+///
+/// This is a deliberate cap rather than a storage limit. Every vote and the reveal read and
+/// rewrite the whole encrypted ballot box inside MPC, and the reveal runs each elimination round
+/// over every stored ballot, so the cost of each computation grows with the cap. Electorates
+/// larger than this should use a tally-based ranked poll (`create_ranked_poll`) instead.
+const MAX_IRV_BALLOTS: u8 = 16;
+
+/// Ciphertexts of a multi-option poll's `vote_state` read by its 2-4, 8- and 16-option circuits:
+/// four counter slots (one count each, or four packed counters each), then `num_options`,
+/// `invalid` and `total`, as in the `MultiOptionVoteStats` and `PackedMultiOptionVoteStats16`
+/// circuit structs.
+const MULTI_OPTION_PREFIX_LEN: usize = 7;
+
+/// Ciphertexts of the full multi-option layout of capacity-32 polls, which appends four packed
+/// counter words to the prefix (`PackedMultiOptionVoteStats32`).
+const MULTI_OPTION_STATE_LEN: usize = 11;
+
+/// Accepted epsilon range of noisy-count polls, in thousandths. The circuit caps each geometric
+/// noise draw at 64, which makes a release (epsilon, delta)-DP with
+/// delta <= 2 * e^(-64 * epsilon) * (1 + e^epsilon) per count; the lower bound keeps that delta
+/// below ~5e-7.
+const MIN_DP_EPSILON_MILLIS: u32 = 250;
+const MAX_DP_EPSILON_MILLIS: u32 = 5_000;
 
 declare_id!("DZDFeQuWe8ULjVUjhY7qvPMHo4D2h8YCetv4VwwwE96X");
 
@@ -36,16 +93,40 @@ pub mod voting {
     /// All individual votes remain completely confidential throughout the voting process.
     ///
     /// # Arguments
-    /// * `id` - Unique identifier for this poll
+    /// * `id` - Identifier for this poll, unique among polls created by the payer
     /// * `question` - The poll question voters will respond to
     /// * `nonce` - Cryptographic nonce for initializing encrypted vote counters
+    /// * `starts_at` - Unix timestamp from which votes are accepted
+    /// * `ends_at` - Unix timestamp at which voting closes and reveal becomes possible
+    /// * `permissionless_reveal` - Whether anyone (not just the authority) may reveal after `ends_at`
+    /// * `min_turnout` - Minimum number of ballots for the poll to be decided (0 disables the quorum)
+    /// * `pass_threshold` - Required yes share, e.g. 2/3; `None` for a simple majority
+    /// * `token_gate` - SPL mint and minimum balance a wallet must hold to vote; `None` lets any wallet vote
+    /// * `allowlist_root` - Merkle root of the wallets allowed to vote (see `allowlist`); `None` lets any wallet vote
+    /// * `nft_collection` - Verified collection whose NFT holders may vote; `None` lets any wallet vote
+    /// * `weighted` - Whether ballots count the voter's `token_gate` balance instead of 1 (see `vote_weighted`)
     pub fn create_new_poll(
         ctx: Context<CreateNewPoll>,
         computation_offset: u64,
         id: u32,
         question: String,
         nonce: u128,
+        starts_at: i64,
+        ends_at: i64,
+        permissionless_reveal: bool,
+        min_turnout: u64,
+        pass_threshold: Option<PassThreshold>,
+        token_gate: Option<TokenGate>,
+        allowlist_root: Option<[u8; 32]>,
+        nft_collection: Option<Pubkey>,
+        weighted: bool,
     ) -> Result<()> {
+        require_valid_voting_window(starts_at, ends_at)?;
+        require!(!weighted || token_gate.is_some(), ErrorCode::InvalidWeightedPoll);
+        if let Some(threshold) = pass_threshold {
+            threshold.validate()?;
+        }
+
         msg!("Creating a new poll");
 
         // Initialize the poll account with the provided parameters
@@ -54,7 +135,19 @@ pub mod voting {
         ctx.accounts.poll_acc.id = id;
         ctx.accounts.poll_acc.authority = ctx.accounts.payer.key();
         ctx.accounts.poll_acc.nonce = nonce;
-        ctx.accounts.poll_acc.vote_state = [[0; 32]; 2];
+        ctx.accounts.poll_acc.vote_state = [[0; 32]; 4];
+        ctx.accounts.poll_acc.starts_at = starts_at;
+        ctx.accounts.poll_acc.ends_at = ends_at;
+        ctx.accounts.poll_acc.permissionless_reveal = permissionless_reveal;
+        ctx.accounts.poll_acc.eligibility = Eligibility {
+            token_gate,
+            allowlist_root,
+            nft_collection,
+        };
+        ctx.accounts.poll_acc.weighted = weighted;
+        ctx.accounts.poll_acc.min_turnout = min_turnout;
+        ctx.accounts.poll_acc.pass_threshold = pass_threshold;
+        ctx.accounts.poll_acc.status = PollStatus::Draft;
 
         let args = vec![Argument::PlaintextU128(nonce)];
 
@@ -85,8 +178,10 @@ pub mod voting {
             _ => return Err(ErrorCode::AbortedComputation.into()),
         };
 
-        ctx.accounts.poll_acc.vote_state = o.ciphertexts;
-        ctx.accounts.poll_acc.nonce = o.nonce;
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.status = poll_acc.status.transition_to(PollStatus::Active)?;
+        poll_acc.vote_state = o.ciphertexts;
+        poll_acc.nonce = o.nonce;
 
         Ok(())
     }
@@ -110,11 +205,21 @@ pub mod voting {
     /// This function allows a voter to cast their vote (yes/no) in encrypted form.
     /// The vote is added to the running tally through MPC computation, ensuring
     /// that individual votes remain confidential while updating the overall count.
+    /// Each wallet can vote once per poll; the first vote creates its `VoterRecord`.
+    /// On token-gated polls the voter must pass a token account holding the required balance,
+    /// and on NFT-gated polls an NFT of the poll's verified collection with its metadata account.
+    ///
+    /// Several votes may be in flight at once. Each is computed against the tallies current at
+    /// queue time, so when two overlap only the first to call back is applied; the other is
+    /// dropped as stale. Once the computation is finalized, a `VoterRecord` whose `has_voted`
+    /// was cleared means the ballot was aborted or superseded and not counted, and the wallet
+    /// can submit it again.
     ///
     /// # Arguments
     /// * `vote` - Encrypted vote (true for yes, false for no)
     /// * `vote_encryption_pubkey` - Voter's public key for encryption
     /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - Merkle proof of the voter's wallet; empty on polls without an allowlist
     pub fn vote(
         ctx: Context<Vote>,
         computation_offset: u64,
@@ -122,7 +227,26 @@ pub mod voting {
         vote: [u8; 32],
         vote_encryption_pubkey: [u8; 32],
         vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
     ) -> Result<()> {
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+        require!(!ctx.accounts.poll_acc.weighted, ErrorCode::WrongVoteWeighting);
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
         let args = vec![
             Argument::ArcisPubkey(vote_encryption_pubkey),
             Argument::PlaintextU128(vote_nonce),
@@ -132,7 +256,7 @@ pub mod voting {
                 ctx.accounts.poll_acc.key(),
                 // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
                 8 + 1,
-                32 * 2, // 2 vote counters (yes/no), each stored as 32-byte ciphertext
+                32 * 4, // yes/no/abstain counters + total, each stored as 32-byte ciphertext
             ),
         ];
 
@@ -143,10 +267,10 @@ pub mod voting {
             computation_offset,
             args,
             None,
-            vec![VoteCallback::callback_ix(&[CallbackAccount {
-                pubkey: ctx.accounts.poll_acc.key(),
-                is_writable: true,
-            }])],
+            vec![VoteCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
         )?;
         Ok(())
     }
@@ -156,9 +280,258 @@ pub mod voting {
         ctx: Context<VoteCallback>,
         output: ComputationOutputs<VoteOutput>,
     ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(VoteOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
+        let output = match output {
+            ComputationOutputs::Success(VoteOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        ctx.accounts.poll_acc.vote_state = o.ciphertexts;
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_vote_weighted_comp_def(ctx: Context<InitVoteWeightedCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_weighted_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted vote to a weighted poll.
+    ///
+    /// The vote's weight is the balance of the voter's token account for the poll's
+    /// `token_gate` mint at vote time. The weight is passed to the MPC computation in
+    /// plaintext and added to the yes or no counter, so it is public while the direction
+    /// stays confidential. Only valid on polls created with `weighted`.
+    /// Eligibility gates and the one-vote-per-wallet receipt work as in `vote`.
+    ///
+    /// The balance is read live rather than from a snapshot, so tokens moved to another wallet
+    /// after it voted count again when that wallet votes. Polls that must count each token once
+    /// should gate on a mint whose tokens cannot be transferred during the voting window.
+    ///
+    /// # Arguments
+    /// * `vote` - Encrypted vote (true for yes, false for no)
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_weighted(
+        ctx: Context<VoteWeighted>,
+        computation_offset: u64,
+        _id: u32,
+        vote: [u8; 32],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+        require!(ctx.accounts.poll_acc.weighted, ErrorCode::WrongVoteWeighting);
+        // Weighted polls are token-gated, so the gate check above ensured the account is present
+        let weight = ctx
+            .accounts
+            .voter_token_account
+            .as_ref()
+            .map_or(0, |token_account| token_account.amount);
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+            Argument::EncryptedBool(vote),
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 4, // yes/no/abstain counters + total, each stored as 32-byte ciphertext
+            ),
+            Argument::PlaintextU64(weight),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteWeightedCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_weighted")]
+    pub fn vote_weighted_callback(
+        ctx: Context<VoteWeightedCallback>,
+        output: ComputationOutputs<VoteWeightedOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteWeightedOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        ctx.accounts.poll_acc.vote_state = o.ciphertexts;
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_vote_tristate_comp_def(ctx: Context<InitVoteTristateCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_tristate_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted yes/no/abstain vote to the poll.
+    ///
+    /// Like `vote`, but the voter may abstain: abstentions count toward the poll's minimum
+    /// turnout without affecting the yes/no comparison.
+    /// Eligibility gates and the one-vote-per-wallet receipt work as in `vote`.
+    ///
+    /// # Arguments
+    /// * `choice` - Encrypted choice (0 = no, 1 = yes, 2 = abstain)
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_tristate(
+        ctx: Context<VoteTristate>,
+        computation_offset: u64,
+        _id: u32,
+        choice: [u8; 32],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+        require!(!ctx.accounts.poll_acc.weighted, ErrorCode::WrongVoteWeighting);
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+            Argument::EncryptedU8(choice),
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 4, // yes/no/abstain counters + total, each stored as 32-byte ciphertext
+            ),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteTristateCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_tristate")]
+    pub fn vote_tristate_callback(
+        ctx: Context<VoteTristateCallback>,
+        output: ComputationOutputs<VoteTristateOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteTristateOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
         };
 
         ctx.accounts.poll_acc.vote_state = o.ciphertexts;
@@ -168,6 +541,9 @@ pub mod voting {
         let current_timestamp = clock.unix_timestamp;
 
         emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
             timestamp: current_timestamp,
         });
 
@@ -190,9 +566,11 @@ pub mod voting {
 
     /// Reveals the final result of the poll.
     ///
-    /// Only the poll authority can call this function to decrypt and reveal the vote tallies.
+    /// Only the poll authority can call this function, and only once voting has ended, to
+    /// decrypt and reveal the vote tallies. Polls created with `permissionless_reveal` can be
+    /// revealed by anyone after `ends_at`. The reveal can only be queued once.
     /// The MPC computation compares the yes and no vote counts and returns whether
-    /// the majority voted yes (true) or no (false).
+    /// the majority voted yes or no, or whether the poll ended in a tie.
     ///
     /// # Arguments
     /// * `id` - The poll ID to reveal results for
@@ -202,20 +580,23 @@ pub mod voting {
         id: u32,
     ) -> Result<()> {
         require!(
-            ctx.accounts.payer.key() == ctx.accounts.poll_acc.authority,
-            ErrorCode::InvalidAuthority
+            ctx.accounts.poll_acc.pass_threshold.is_none(),
+            ErrorCode::WrongRevealMode
         );
 
         msg!("Revealing voting result for poll with id {}", id);
 
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
         let args = vec![
             Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
             Argument::Account(
                 ctx.accounts.poll_acc.key(),
                 // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
                 8 + 1,
-                32 * 2, // 2 encrypted vote counters (yes/no), 32 bytes each
+                32 * 4, // encrypted yes/no/abstain counters + total, 32 bytes each
             ),
+            Argument::PlaintextU64(ctx.accounts.poll_acc.min_turnout),
         ];
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
@@ -225,7 +606,10 @@ pub mod voting {
             computation_offset,
             args,
             None,
-            vec![RevealResultCallback::callback_ix(&[])],
+            vec![RevealResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
         )?;
         Ok(())
     }
@@ -235,16 +619,154 @@ pub mod voting {
         ctx: Context<RevealResultCallback>,
         output: ComputationOutputs<RevealResultOutput>,
     ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(RevealResultOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
+        let outcome = match output {
+            ComputationOutputs::Success(RevealResultOutput { field_0 }) => {
+                PollOutcome::from_code(field_0)
+            }
+            _ => None,
+        };
+        // An unknown code is handled like an abort, so the poll is not left stuck in Revealing
+        let Some(outcome) = outcome else {
+            return ctx.accounts.poll_acc.lifecycle().abort_reveal();
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.result = outcome;
+
+        emit!(RevealResultEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            output: outcome,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_reveal_threshold_result_comp_def(ctx: Context<InitRevealThresholdResultCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_threshold_result_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals whether a poll created with a `pass_threshold` passed.
+    ///
+    /// Same access rules as `reveal_result`. The MPC computation checks the yes share
+    /// against the poll's threshold and reveals only `Yes` (passed) or `No` (failed),
+    /// or `QuorumNotMet` if the minimum turnout was not reached.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_threshold_result(
+        ctx: Context<RevealThresholdVotingResult>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        let threshold = ctx
+            .accounts
+            .poll_acc
+            .pass_threshold
+            .ok_or(ErrorCode::WrongRevealMode)?;
+
+        msg!("Revealing threshold result for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 4, // encrypted yes/no/abstain counters + total, 32 bytes each
+            ),
+            Argument::PlaintextU64(ctx.accounts.poll_acc.min_turnout),
+            Argument::PlaintextU64(threshold.numerator),
+            Argument::PlaintextU64(threshold.denominator),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealThresholdResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_threshold_result")]
+    pub fn reveal_threshold_result_callback(
+        ctx: Context<RevealThresholdResultCallback>,
+        output: ComputationOutputs<RevealThresholdResultOutput>,
+    ) -> Result<()> {
+        let outcome = match output {
+            ComputationOutputs::Success(RevealThresholdResultOutput { field_0 }) => {
+                PollOutcome::from_code(field_0)
+            }
+            _ => None,
+        };
+        // An unknown code is handled like an abort, so the poll is not left stuck in Revealing
+        let Some(outcome) = outcome else {
+            return ctx.accounts.poll_acc.lifecycle().abort_reveal();
         };
 
-        emit!(RevealResultEvent { output: o });
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.result = outcome;
+
+        emit!(RevealResultEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            output: outcome,
+        });
 
         Ok(())
     }
 
+    /// Closes a voter's receipt once the poll is revealed or cancelled, returning the rent to the voter.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID the receipt belongs to
+    pub fn close_voter_record(ctx: Context<CloseVoterRecord>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.status.require_finalized()
+    }
+
+    /// Stops a poll from accepting further votes.
+    ///
+    /// The authority can close the poll at any time; once `ends_at` has passed anyone can.
+    /// Results still cannot be revealed before `ends_at`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to close
+    pub fn close_poll(ctx: Context<UpdatePollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().close(ctx.accounts.signer.key())
+    }
+
+    /// Cancels a poll so it can never be revealed. Only the poll authority can cancel,
+    /// and only before the reveal has been queued.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to cancel
+    pub fn cancel_poll(ctx: Context<UpdatePollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().cancel(ctx.accounts.signer.key())
+    }
+
     // ==================== MULTI-OPTION POLL INSTRUCTIONS ====================
 
     pub fn init_multi_option_vote_stats_comp_def(ctx: Context<InitMultiOptionVoteStatsCompDef>) -> Result<()> {
@@ -261,16 +783,40 @@ pub mod voting {
         Ok(())
     }
 
-    /// Creates a new multi-option poll (2-4 options) for DAO voting.
+    /// Creates a new multi-option poll (2-32 options) for DAO voting.
     ///
     /// This initializes a multi-option poll account and sets up the encrypted vote counters.
     /// Each option gets its own encrypted counter, and all votes remain confidential until reveal.
     ///
+    /// The poll's capacity is picked from the number of options. Polls with up to 4 options
+    /// support every ballot type, reveal mode and weighting. Larger polls (capacity 8, 16 or 32)
+    /// pack four counters per ciphertext and only have single-choice vote and full-count reveal
+    /// circuits, so they must be unweighted `SingleChoice`/`FullCounts` polls; they are voted on
+    /// with `vote_multi_option_8`, `_16` or `_32` and revealed with the matching
+    /// `reveal_multi_option_*_result`.
+    ///
     /// # Arguments
-    /// * `id` - Unique identifier for this poll
+    /// * `id` - Identifier for this poll, unique among polls created by the payer
     /// * `question` - The poll question
-    /// * `options` - Array of 2-4 option strings
+    /// * `options` - Array of 2-32 option strings
     /// * `nonce` - Cryptographic nonce for initializing encrypted vote counters
+    /// * `starts_at` - Unix timestamp from which votes are accepted
+    /// * `ends_at` - Unix timestamp at which voting closes and reveal becomes possible
+    /// * `permissionless_reveal` - Whether anyone (not just the authority) may reveal after `ends_at`
+    /// * `ballot_type` - Whether voters pick a single option, approve any subset of options or
+    ///   spread quadratic votes
+    /// * `credit_budget` - Credits each voter may spend on `BallotType::Quadratic` polls, 0 for other types
+    /// * `reveal_mode` - Whether the reveal discloses every count, noisy counts or only the winner
+    /// * `dp_epsilon_millis` - Privacy parameter of `RevealMode::NoisyCounts` polls in thousandths
+    ///   (250-5000), 0 for other modes. Noisy reveals are never weighted
+    /// * `min_turnout` - Minimum number of ballots for the counts to be disclosed (0 disables the quorum)
+    /// * `min_ballots` - k-anonymity threshold: with fewer ballots the reveal only reports
+    ///   insufficient participation, never the counts (0 disables the guard)
+    /// * `token_gate` - SPL mint and minimum balance a wallet must hold to vote; `None` lets any wallet vote
+    /// * `allowlist_root` - Merkle root of the wallets allowed to vote (see `allowlist`); `None` lets any wallet vote
+    /// * `nft_collection` - Verified collection whose NFT holders may vote; `None` lets any wallet vote
+    /// * `weighted` - Whether ballots count the voter's `token_gate` balance instead of 1 (see
+    ///   `vote_multi_option_weighted`); needs single-choice ballots and exact reveals
     pub fn create_multi_option_poll(
         ctx: Context<CreateMultiOptionPoll>,
         computation_offset: u64,
@@ -278,10 +824,59 @@ pub mod voting {
         question: String,
         options: Vec<String>,
         nonce: u128,
+        starts_at: i64,
+        ends_at: i64,
+        permissionless_reveal: bool,
+        ballot_type: BallotType,
+        credit_budget: u64,
+        reveal_mode: RevealMode,
+        dp_epsilon_millis: u32,
+        min_turnout: u64,
+        min_ballots: u64,
+        token_gate: Option<TokenGate>,
+        allowlist_root: Option<[u8; 32]>,
+        nft_collection: Option<Pubkey>,
+        weighted: bool,
     ) -> Result<()> {
-        require!(options.len() >= 2 && options.len() <= 4, ErrorCode::InvalidOptionCount);
-        
-        msg!("Creating a new multi-option poll with {} options", options.len());
+        let capacity =
+            multi_option_capacity(options.len()).ok_or(ErrorCode::InvalidMultiOptionCount)?;
+        require_valid_voting_window(starts_at, ends_at)?;
+        // Packed polls only have single-choice vote and full-count reveal circuits, and their
+        // 32-bit counter lanes are only sized for unweighted ballots
+        require!(
+            capacity == 4
+                || (ballot_type == BallotType::SingleChoice
+                    && reveal_mode == RevealMode::FullCounts
+                    && !weighted),
+            ErrorCode::UnsupportedLargePoll
+        );
+        if ballot_type == BallotType::Quadratic {
+            require!(credit_budget > 0, ErrorCode::InvalidCreditBudget);
+        } else {
+            require!(credit_budget == 0, ErrorCode::InvalidCreditBudget);
+        }
+        if reveal_mode == RevealMode::NoisyCounts {
+            // A quadratic or weighted ballot can move a count by far more than the noise is
+            // calibrated for
+            require!(ballot_type != BallotType::Quadratic, ErrorCode::WrongBallotType);
+            require!(!weighted, ErrorCode::InvalidWeightedPoll);
+            require!(
+                (MIN_DP_EPSILON_MILLIS..=MAX_DP_EPSILON_MILLIS).contains(&dp_epsilon_millis),
+                ErrorCode::InvalidEpsilon
+            );
+        } else {
+            require!(dp_epsilon_millis == 0, ErrorCode::InvalidEpsilon);
+        }
+        require!(
+            !weighted || (token_gate.is_some() && ballot_type == BallotType::SingleChoice),
+            ErrorCode::InvalidWeightedPoll
+        );
+
+        msg!(
+            "Creating a new multi-option poll with {} options (capacity {})",
+            options.len(),
+            capacity
+        );
 
         // Initialize the poll account with the provided parameters
         ctx.accounts.poll_acc.question = question;
@@ -290,12 +885,29 @@ pub mod voting {
         ctx.accounts.poll_acc.id = id;
         ctx.accounts.poll_acc.authority = ctx.accounts.payer.key();
         ctx.accounts.poll_acc.nonce = nonce;
-        ctx.accounts.poll_acc.vote_state = [[0; 32]; 5]; // 4 option counters + num_options
+        ctx.accounts.poll_acc.vote_state = [[0; 32]; 11]; // counters + num_options + invalid + total
         ctx.accounts.poll_acc.num_options = ctx.accounts.poll_acc.options.len() as u8;
+        ctx.accounts.poll_acc.capacity = capacity;
+        ctx.accounts.poll_acc.ballot_type = ballot_type;
+        ctx.accounts.poll_acc.credit_budget = credit_budget;
+        ctx.accounts.poll_acc.reveal_mode = reveal_mode;
+        ctx.accounts.poll_acc.dp_epsilon_millis = dp_epsilon_millis;
+        ctx.accounts.poll_acc.min_turnout = min_turnout;
+        ctx.accounts.poll_acc.min_ballots = min_ballots;
+        ctx.accounts.poll_acc.starts_at = starts_at;
+        ctx.accounts.poll_acc.ends_at = ends_at;
+        ctx.accounts.poll_acc.permissionless_reveal = permissionless_reveal;
+        ctx.accounts.poll_acc.eligibility = Eligibility {
+            token_gate,
+            allowlist_root,
+            nft_collection,
+        };
+        ctx.accounts.poll_acc.weighted = weighted;
+        ctx.accounts.poll_acc.status = PollStatus::Draft;
 
         let args = vec![
             Argument::PlaintextU128(nonce),
-            Argument::PlaintextU128(ctx.accounts.poll_acc.num_options as u128),
+            Argument::PlaintextU8(ctx.accounts.poll_acc.num_options),
         ];
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
@@ -325,8 +937,11 @@ pub mod voting {
             _ => return Err(ErrorCode::AbortedComputation.into()),
         };
 
-        ctx.accounts.poll_acc.vote_state = o.ciphertexts;
-        ctx.accounts.poll_acc.nonce = o.nonce;
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.status = poll_acc.status.transition_to(PollStatus::Active)?;
+        let state: [[u8; 32]; MULTI_OPTION_STATE_LEN] = o.ciphertexts;
+        poll_acc.vote_state = state;
+        poll_acc.nonce = o.nonce;
 
         Ok(())
     }
@@ -348,12 +963,15 @@ pub mod voting {
     /// Submits an encrypted vote to a multi-option poll.
     ///
     /// The voter selects one option (0-3), which is encrypted and added to the
-    /// corresponding counter through MPC. Individual votes remain confidential.
+    /// corresponding counter through MPC; out-of-range selections are counted as invalid.
+    /// Only valid on `BallotType::SingleChoice` polls. Individual votes remain confidential.
+    /// Eligibility gates and the one-vote-per-wallet receipt work as in `vote`.
     ///
     /// # Arguments
     /// * `selected_option_encrypted` - Encrypted selected option index (0-3)
     /// * `vote_encryption_pubkey` - Voter's public key for encryption
     /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
     pub fn vote_multi_option(
         ctx: Context<VoteMultiOption>,
         computation_offset: u64,
@@ -361,7 +979,34 @@ pub mod voting {
         selected_option_encrypted: [u8; 32],
         vote_encryption_pubkey: [u8; 32],
         vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
     ) -> Result<()> {
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+        require!(!ctx.accounts.poll_acc.weighted, ErrorCode::WrongVoteWeighting);
+        require!(
+            ctx.accounts.poll_acc.ballot_type == BallotType::SingleChoice,
+            ErrorCode::WrongBallotType
+        );
+        require!(
+            ctx.accounts.poll_acc.capacity == 4,
+            ErrorCode::PollCapacityMismatch
+        );
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
         let args = vec![
             Argument::ArcisPubkey(vote_encryption_pubkey),
             Argument::PlaintextU128(vote_nonce),
@@ -371,7 +1016,7 @@ pub mod voting {
                 ctx.accounts.poll_acc.key(),
                 // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
                 8 + 1,
-                32 * 5, // 4 vote counters + num_options, each stored as 32-byte ciphertext
+                32 * 7, // 4 vote counters + num_options + invalid + total, each stored as 32-byte ciphertext
             ),
         ];
 
@@ -382,10 +1027,10 @@ pub mod voting {
             computation_offset,
             args,
             None,
-            vec![VoteMultiOptionCallback::callback_ix(&[CallbackAccount {
-                pubkey: ctx.accounts.poll_acc.key(),
-                is_writable: true,
-            }])],
+            vec![VoteMultiOptionCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
         )?;
         Ok(())
     }
@@ -395,18 +1040,418 @@ pub mod voting {
         ctx: Context<VoteMultiOptionCallback>,
         output: ComputationOutputs<VoteMultiOptionOutput>,
     ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(VoteMultiOptionOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
+        let output = match output {
+            ComputationOutputs::Success(VoteMultiOptionOutput { field_0 }) => Some(field_0),
+            _ => None,
         };
 
-        ctx.accounts.poll_acc.vote_state = o.ciphertexts;
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        // Only compiles while the circuit's layout has the length of the shared prefix
+        let prefix: [[u8; 32]; MULTI_OPTION_PREFIX_LEN] = o.ciphertexts;
+        ctx.accounts.poll_acc.vote_state[..MULTI_OPTION_PREFIX_LEN].copy_from_slice(&prefix);
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_vote_multi_option_weighted_comp_def(ctx: Context<InitVoteMultiOptionWeightedCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_multi_option_weighted_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted vote to a weighted multi-option poll.
+    ///
+    /// Like `vote_multi_option`, but the selected option's counter grows by the balance of
+    /// the voter's token account for the poll's `token_gate` mint at vote time. The weight
+    /// is public while the selected option stays confidential. Only valid on polls created
+    /// with `weighted`. Balances are read live, with the caveat described on `vote_weighted`.
+    /// Eligibility gates and the one-vote-per-wallet receipt work as in `vote`.
+    ///
+    /// # Arguments
+    /// * `selected_option_encrypted` - Encrypted selected option index (0-3)
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_multi_option_weighted(
+        ctx: Context<VoteMultiOptionWeighted>,
+        computation_offset: u64,
+        _id: u32,
+        selected_option_encrypted: [u8; 32],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+        require!(ctx.accounts.poll_acc.weighted, ErrorCode::WrongVoteWeighting);
+        // Weighted polls are token-gated, so the gate check above ensured the account is present
+        let weight = ctx
+            .accounts
+            .voter_token_account
+            .as_ref()
+            .map_or(0, |token_account| token_account.amount);
+        require!(
+            ctx.accounts.poll_acc.ballot_type == BallotType::SingleChoice,
+            ErrorCode::WrongBallotType
+        );
+        require!(
+            ctx.accounts.poll_acc.capacity == 4,
+            ErrorCode::PollCapacityMismatch
+        );
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+            Argument::EncryptedU8(selected_option_encrypted),
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 7, // 4 vote counters + num_options + invalid + total, each stored as 32-byte ciphertext
+            ),
+            Argument::PlaintextU64(weight),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteMultiOptionWeightedCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_multi_option_weighted")]
+    pub fn vote_multi_option_weighted_callback(
+        ctx: Context<VoteMultiOptionWeightedCallback>,
+        output: ComputationOutputs<VoteMultiOptionWeightedOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteMultiOptionWeightedOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        // Only compiles while the circuit's layout has the length of the shared prefix
+        let prefix: [[u8; 32]; MULTI_OPTION_PREFIX_LEN] = o.ciphertexts;
+        ctx.accounts.poll_acc.vote_state[..MULTI_OPTION_PREFIX_LEN].copy_from_slice(&prefix);
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_vote_approval_comp_def(ctx: Context<InitVoteApprovalCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_approval_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted approval ballot to a multi-option poll.
+    ///
+    /// The voter marks any subset of the options; each approved option's counter is
+    /// incremented through MPC, so nomination rounds can back several candidates at once.
+    /// Only valid on `BallotType::Approval` polls. Results are revealed with
+    /// `reveal_multi_option_result` like any other multi-option poll.
+    ///
+    /// # Arguments
+    /// * `approvals_encrypted` - Encrypted approval flag for each of the 4 option slots
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_approval(
+        ctx: Context<VoteApproval>,
+        computation_offset: u64,
+        _id: u32,
+        approvals_encrypted: [[u8; 32]; 4],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+        require!(
+            ctx.accounts.poll_acc.ballot_type == BallotType::Approval,
+            ErrorCode::WrongBallotType
+        );
+        require!(
+            ctx.accounts.poll_acc.capacity == 4,
+            ErrorCode::PollCapacityMismatch
+        );
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let mut args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+        ];
+        for approval in approvals_encrypted {
+            args.push(Argument::EncryptedBool(approval));
+        }
+        args.push(Argument::PlaintextU128(ctx.accounts.poll_acc.nonce));
+        args.push(Argument::Account(
+            ctx.accounts.poll_acc.key(),
+            // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+            8 + 1,
+            32 * 7, // 4 vote counters + num_options + invalid + total, each stored as 32-byte ciphertext
+        ));
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteApprovalCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_approval")]
+    pub fn vote_approval_callback(
+        ctx: Context<VoteApprovalCallback>,
+        output: ComputationOutputs<VoteApprovalOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteApprovalOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        // Only compiles while the circuit's layout has the length of the shared prefix
+        let prefix: [[u8; 32]; MULTI_OPTION_PREFIX_LEN] = o.ciphertexts;
+        ctx.accounts.poll_acc.vote_state[..MULTI_OPTION_PREFIX_LEN].copy_from_slice(&prefix);
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_vote_quadratic_comp_def(ctx: Context<InitVoteQuadraticCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_quadratic_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted quadratic ballot to a multi-option poll.
+    ///
+    /// The voter spreads votes across the options, where `v` votes for one option cost
+    /// `v * v` credits. The MPC computation checks the allocation against the poll's
+    /// `credit_budget` without revealing it; over-budget ballots are counted as invalid.
+    /// Only valid on `BallotType::Quadratic` polls. Results are revealed with
+    /// `reveal_multi_option_result` like any other multi-option poll.
+    ///
+    /// # Arguments
+    /// * `votes_encrypted` - Encrypted number of votes (0-255) for each of the 4 option slots
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_quadratic(
+        ctx: Context<VoteQuadratic>,
+        computation_offset: u64,
+        _id: u32,
+        votes_encrypted: [[u8; 32]; 4],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+        require!(
+            ctx.accounts.poll_acc.ballot_type == BallotType::Quadratic,
+            ErrorCode::WrongBallotType
+        );
+        require!(
+            ctx.accounts.poll_acc.capacity == 4,
+            ErrorCode::PollCapacityMismatch
+        );
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let mut args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+        ];
+        for votes in votes_encrypted {
+            args.push(Argument::EncryptedU8(votes));
+        }
+        args.push(Argument::PlaintextU128(ctx.accounts.poll_acc.nonce));
+        args.push(Argument::Account(
+            ctx.accounts.poll_acc.key(),
+            // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+            8 + 1,
+            32 * 7, // 4 vote counters + num_options + invalid + total, each stored as 32-byte ciphertext
+        ));
+        args.push(Argument::PlaintextU64(ctx.accounts.poll_acc.credit_budget));
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteQuadraticCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_quadratic")]
+    pub fn vote_quadratic_callback(
+        ctx: Context<VoteQuadraticCallback>,
+        output: ComputationOutputs<VoteQuadraticOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteQuadraticOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        // Only compiles while the circuit's layout has the length of the shared prefix
+        let prefix: [[u8; 32]; MULTI_OPTION_PREFIX_LEN] = o.ciphertexts;
+        ctx.accounts.poll_acc.vote_state[..MULTI_OPTION_PREFIX_LEN].copy_from_slice(&prefix);
         ctx.accounts.poll_acc.nonce = o.nonce;
 
         let clock = Clock::get()?;
         let current_timestamp = clock.unix_timestamp;
 
         emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
             timestamp: current_timestamp,
         });
 
@@ -429,8 +1474,13 @@ pub mod voting {
 
     /// Reveals the vote counts for a multi-option poll.
     ///
-    /// Only the poll authority can call this function to decrypt and reveal
-    /// the vote counts for all options. Returns raw counts array [u64; 4].
+    /// Only the poll authority can call this function, and only once voting has ended, to
+    /// decrypt and reveal the vote counts for all options. Returns raw counts array [u64; 4]
+    /// along with the number of malformed ballots that were routed to the invalid counter.
+    /// With fewer than `min_ballots` ballots only `sufficient_participation = false` is
+    /// disclosed and all counts are reported as 0, so small polls cannot be de-anonymized.
+    /// Polls created with `permissionless_reveal` can be revealed by anyone after `ends_at`.
+    /// The reveal can only be queued once.
     ///
     /// # Arguments
     /// * `id` - The poll ID to reveal results for
@@ -440,20 +1490,28 @@ pub mod voting {
         id: u32,
     ) -> Result<()> {
         require!(
-            ctx.accounts.payer.key() == ctx.accounts.poll_acc.authority,
-            ErrorCode::InvalidAuthority
+            ctx.accounts.poll_acc.capacity == 4,
+            ErrorCode::PollCapacityMismatch
+        );
+        require!(
+            ctx.accounts.poll_acc.reveal_mode == RevealMode::FullCounts,
+            ErrorCode::WrongRevealMode
         );
 
         msg!("Revealing multi-option voting result for poll with id {}", id);
 
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
         let args = vec![
             Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
             Argument::Account(
                 ctx.accounts.poll_acc.key(),
                 // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
                 8 + 1,
-                32 * 5, // 4 encrypted vote counters + num_options, 32 bytes each
+                32 * 7, // 4 encrypted vote counters + num_options + invalid + total, 32 bytes each
             ),
+            Argument::PlaintextU64(ctx.accounts.poll_acc.min_turnout),
+            Argument::PlaintextU64(ctx.accounts.poll_acc.min_ballots),
         ];
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
@@ -463,7 +1521,10 @@ pub mod voting {
             computation_offset,
             args,
             None,
-            vec![RevealMultiOptionResultCallback::callback_ix(&[])],
+            vec![RevealMultiOptionResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
         )?;
         Ok(())
     }
@@ -473,70 +1534,4473 @@ pub mod voting {
         ctx: Context<RevealMultiOptionResultCallback>,
         output: ComputationOutputs<RevealMultiOptionResultOutput>,
     ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(RevealMultiOptionResultOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
+        let (counts, invalid, quorum_met, sufficient_participation) = match output {
+            ComputationOutputs::Success(RevealMultiOptionResultOutput {
+                field_0: RevealMultiOptionResultOutputStruct0 {
+                    field_0: counts,
+                    field_1: invalid,
+                    field_2: quorum_met,
+                    field_3: sufficient_participation,
+                },
+            }) => (counts, invalid, quorum_met, sufficient_participation),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
         };
 
-        emit!(RevealMultiOptionResultEvent { 
-            option_1_count: o[0],
-            option_2_count: o[1],
-            option_3_count: o[2],
-            option_4_count: o[3],
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.option_counts[..4].copy_from_slice(&counts);
+        poll_acc.invalid_ballots = invalid;
+        poll_acc.quorum_met = quorum_met;
+        poll_acc.sufficient_participation = sufficient_participation;
+
+        emit!(RevealMultiOptionResultEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            option_1_count: counts[0],
+            option_2_count: counts[1],
+            option_3_count: counts[2],
+            option_4_count: counts[3],
+            invalid_count: invalid,
+            quorum_met,
+            sufficient_participation,
         });
 
         Ok(())
     }
-}
 
-#[queue_computation_accounts("init_vote_stats", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64, id: u32)]
-pub struct CreateNewPoll<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Account<'info, MXEAccount>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
-    )]
-    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Account<'info, Cluster>,
-    #[account(
-        mut,
+    pub fn init_reveal_multi_option_winner_comp_def(ctx: Context<InitRevealMultiOptionWinnerCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_multi_option_winner_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals only the winner of a multi-option poll created with `RevealMode::WinnerOnly`.
+    ///
+    /// Same access rules as `reveal_multi_option_result`, but the MPC computation discloses
+    /// just the winning option, whether the top count is tied, and a coarse margin bucket
+    /// (0 = tie, 1 = under 10%, 2 = 10-25%, 3 = 25% or more of all valid votes).
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_multi_option_winner(
+        ctx: Context<RevealMultiOptionWinner>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 4,
+            ErrorCode::PollCapacityMismatch
+        );
+        require!(
+            ctx.accounts.poll_acc.reveal_mode == RevealMode::WinnerOnly,
+            ErrorCode::WrongRevealMode
+        );
+
+        msg!("Revealing multi-option winner for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 7, // 4 encrypted vote counters + num_options + invalid + total, 32 bytes each
+            ),
+            Argument::PlaintextU64(ctx.accounts.poll_acc.min_turnout),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealMultiOptionWinnerCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_multi_option_winner")]
+    pub fn reveal_multi_option_winner_callback(
+        ctx: Context<RevealMultiOptionWinnerCallback>,
+        output: ComputationOutputs<RevealMultiOptionWinnerOutput>,
+    ) -> Result<()> {
+        let (winner, is_tie, margin_bucket, quorum_met) = match output {
+            ComputationOutputs::Success(RevealMultiOptionWinnerOutput {
+                field_0: RevealMultiOptionWinnerOutputStruct0 {
+                    field_0: winner,
+                    field_1: is_tie,
+                    field_2: margin_bucket,
+                    field_3: quorum_met,
+                },
+            }) => (winner, is_tie, margin_bucket, quorum_met),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.winner = winner;
+        poll_acc.is_tie = is_tie;
+        poll_acc.margin_bucket = margin_bucket;
+        poll_acc.quorum_met = quorum_met;
+
+        emit!(RevealMultiOptionWinnerEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            winner,
+            is_tie,
+            margin_bucket,
+            quorum_met,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_reveal_multi_option_result_dp_comp_def(ctx: Context<InitRevealMultiOptionResultDpCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_multi_option_result_dp_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals differentially private counts of a multi-option poll created with
+    /// `RevealMode::NoisyCounts`.
+    ///
+    /// Same access rules as `reveal_multi_option_result`, but every count is released with
+    /// truncated discrete Laplace noise of scale 1/epsilon generated inside the MPC
+    /// computation, so a single ballot cannot be inferred from the result even in small polls.
+    /// The release is (epsilon, delta)-DP per count, with delta bounded as described on
+    /// `MIN_DP_EPSILON_MILLIS`. The privacy budget spent by the release is reported in
+    /// `RevealMultiOptionDpResultEvent`. The poll's `min_turnout` and `min_ballots` gate the
+    /// release as in `reveal_multi_option_result`: if either is missed, all counts are 0.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_multi_option_result_dp(
+        ctx: Context<RevealMultiOptionResultDp>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 4,
+            ErrorCode::PollCapacityMismatch
+        );
+        require!(
+            ctx.accounts.poll_acc.reveal_mode == RevealMode::NoisyCounts,
+            ErrorCode::WrongRevealMode
+        );
+
+        msg!("Revealing noisy multi-option counts for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 7, // 4 encrypted vote counters + num_options + invalid + total, 32 bytes each
+            ),
+            Argument::PlaintextU128(dp_continue_probability(ctx.accounts.poll_acc.dp_epsilon_millis)),
+            Argument::PlaintextU64(ctx.accounts.poll_acc.min_turnout),
+            Argument::PlaintextU64(ctx.accounts.poll_acc.min_ballots),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealMultiOptionResultDpCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_multi_option_result_dp")]
+    pub fn reveal_multi_option_result_dp_callback(
+        ctx: Context<RevealMultiOptionResultDpCallback>,
+        output: ComputationOutputs<RevealMultiOptionResultDpOutput>,
+    ) -> Result<()> {
+        let (noisy_counts, quorum_met, sufficient_participation) = match output {
+            ComputationOutputs::Success(RevealMultiOptionResultDpOutput {
+                field_0: RevealMultiOptionResultDpOutputStruct0 {
+                    field_0: noisy_counts,
+                    field_1: quorum_met,
+                    field_2: sufficient_participation,
+                },
+            }) => (noisy_counts, quorum_met, sufficient_participation),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.option_counts[..4].copy_from_slice(&noisy_counts);
+        poll_acc.quorum_met = quorum_met;
+        poll_acc.sufficient_participation = sufficient_participation;
+
+        // A single-choice ballot moves one count; an approval ballot can move every count
+        let ballot_sensitivity = match poll_acc.ballot_type {
+            BallotType::SingleChoice => 1,
+            BallotType::Approval => poll_acc.num_options as u32,
+            // Not accepted at creation; bounded by the 255 votes a ballot can cast per option
+            BallotType::Quadratic => poll_acc.num_options as u32 * u8::MAX as u32,
+        };
+
+        emit!(RevealMultiOptionDpResultEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            noisy_counts,
+            epsilon_millis: poll_acc.dp_epsilon_millis,
+            privacy_budget_millis: poll_acc.dp_epsilon_millis * ballot_sensitivity,
+            quorum_met,
+            sufficient_participation,
+        });
+
+        Ok(())
+    }
+
+    /// Closes a voter's receipt on a multi-option poll; see `close_voter_record`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID the receipt belongs to
+    pub fn close_multi_option_voter_record(ctx: Context<CloseMultiOptionVoterRecord>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.status.require_finalized()
+    }
+
+    /// Stops a multi-option poll from accepting further votes; see `close_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to close
+    pub fn close_multi_option_poll(ctx: Context<UpdateMultiOptionPollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().close(ctx.accounts.signer.key())
+    }
+
+    /// Cancels a multi-option poll; see `cancel_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to cancel
+    pub fn cancel_multi_option_poll(ctx: Context<UpdateMultiOptionPollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().cancel(ctx.accounts.signer.key())
+    }
+
+    // ==================== LARGE MULTI-OPTION POLL INSTRUCTIONS ====================
+    //
+    // Polls with 5-32 options keep their counters packed four to a u128, with one vote/reveal
+    // circuit per capacity (8, 16 or 32). Arcium binds each instruction to a single circuit,
+    // hence one thin instruction per capacity over the shared `packed_*` helpers.
+
+    pub fn init_vote_multi_option_8_comp_def(ctx: Context<InitVoteMultiOption8CompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_multi_option_8_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted vote to a multi-option poll with capacity 8.
+    ///
+    /// # Arguments
+    /// * `selected_option_encrypted` - Encrypted selected option index
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_multi_option_8(
+        ctx: Context<VoteMultiOption8>,
+        computation_offset: u64,
+        _id: u32,
+        selected_option_encrypted: [u8; 32],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 8,
+            ErrorCode::PollCapacityMismatch
+        );
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let args = packed_vote_args(
+            &ctx.accounts.poll_acc,
+            selected_option_encrypted,
+            vote_encryption_pubkey,
+            vote_nonce,
+        );
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteMultiOption8Callback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_multi_option_8")]
+    pub fn vote_multi_option_8_callback(
+        ctx: Context<VoteMultiOption8Callback>,
+        output: ComputationOutputs<VoteMultiOption8Output>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteMultiOption8Output { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        // Only compiles while the circuit's layout has the length of the shared prefix
+        let prefix: [[u8; 32]; MULTI_OPTION_PREFIX_LEN] = o.ciphertexts;
+        ctx.accounts.poll_acc.vote_state[..MULTI_OPTION_PREFIX_LEN].copy_from_slice(&prefix);
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_vote_multi_option_16_comp_def(ctx: Context<InitVoteMultiOption16CompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_multi_option_16_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted vote to a multi-option poll with capacity 16.
+    ///
+    /// # Arguments
+    /// * `selected_option_encrypted` - Encrypted selected option index
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_multi_option_16(
+        ctx: Context<VoteMultiOption16>,
+        computation_offset: u64,
+        _id: u32,
+        selected_option_encrypted: [u8; 32],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 16,
+            ErrorCode::PollCapacityMismatch
+        );
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let args = packed_vote_args(
+            &ctx.accounts.poll_acc,
+            selected_option_encrypted,
+            vote_encryption_pubkey,
+            vote_nonce,
+        );
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteMultiOption16Callback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_multi_option_16")]
+    pub fn vote_multi_option_16_callback(
+        ctx: Context<VoteMultiOption16Callback>,
+        output: ComputationOutputs<VoteMultiOption16Output>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteMultiOption16Output { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        // Only compiles while the circuit's layout has the length of the shared prefix
+        let prefix: [[u8; 32]; MULTI_OPTION_PREFIX_LEN] = o.ciphertexts;
+        ctx.accounts.poll_acc.vote_state[..MULTI_OPTION_PREFIX_LEN].copy_from_slice(&prefix);
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_vote_multi_option_32_comp_def(ctx: Context<InitVoteMultiOption32CompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_multi_option_32_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted vote to a multi-option poll with capacity 32.
+    ///
+    /// # Arguments
+    /// * `selected_option_encrypted` - Encrypted selected option index
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    /// * `allowlist_proof` - As in `vote`
+    pub fn vote_multi_option_32(
+        ctx: Context<VoteMultiOption32>,
+        computation_offset: u64,
+        _id: u32,
+        selected_option_encrypted: [u8; 32],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 32,
+            ErrorCode::PollCapacityMismatch
+        );
+        ctx.accounts.poll_acc.eligibility.require_can_vote(&VoterCredentials {
+            voter: ctx.accounts.payer.key(),
+            token_account: ctx.accounts.voter_token_account.as_deref(),
+            allowlist_proof: &allowlist_proof,
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+        })?;
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let args = packed_vote_args(
+            &ctx.accounts.poll_acc,
+            selected_option_encrypted,
+            vote_encryption_pubkey,
+            vote_nonce,
+        );
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteMultiOption32Callback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_multi_option_32")]
+    pub fn vote_multi_option_32_callback(
+        ctx: Context<VoteMultiOption32Callback>,
+        output: ComputationOutputs<VoteMultiOption32Output>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteMultiOption32Output { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        // Only compiles while the circuit's layout has the length of the full multi-option state
+        let state: [[u8; 32]; MULTI_OPTION_STATE_LEN] = o.ciphertexts;
+        ctx.accounts.poll_acc.vote_state = state;
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_reveal_multi_option_8_result_comp_def(ctx: Context<InitRevealMultiOption8ResultCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_multi_option_8_result_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals the vote counts of a multi-option poll with capacity 8.
+    ///
+    /// Same access rules as `reveal_multi_option_result`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_multi_option_8_result(
+        ctx: Context<RevealMultiOption8VotingResult>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 8,
+            ErrorCode::PollCapacityMismatch
+        );
+
+        msg!("Revealing multi-option voting result for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = packed_reveal_args(&ctx.accounts.poll_acc);
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealMultiOption8ResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_multi_option_8_result")]
+    pub fn reveal_multi_option_8_result_callback(
+        ctx: Context<RevealMultiOption8ResultCallback>,
+        output: ComputationOutputs<RevealMultiOption8ResultOutput>,
+    ) -> Result<()> {
+        let (packed_counts, invalid, quorum_met, sufficient_participation) = match output {
+            ComputationOutputs::Success(RevealMultiOption8ResultOutput {
+                field_0: RevealMultiOption8ResultOutputStruct0 {
+                    field_0: packed_counts,
+                    field_1: invalid,
+                    field_2: quorum_met,
+                    field_3: sufficient_participation,
+                },
+            }) => (packed_counts, invalid, quorum_met, sufficient_participation),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        store_packed_reveal(
+            &mut ctx.accounts.poll_acc,
+            &packed_counts,
+            invalid,
+            quorum_met,
+            sufficient_participation,
+        )
+    }
+
+    pub fn init_reveal_multi_option_16_result_comp_def(ctx: Context<InitRevealMultiOption16ResultCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_multi_option_16_result_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals the vote counts of a multi-option poll with capacity 16.
+    ///
+    /// Same access rules as `reveal_multi_option_result`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_multi_option_16_result(
+        ctx: Context<RevealMultiOption16VotingResult>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 16,
+            ErrorCode::PollCapacityMismatch
+        );
+
+        msg!("Revealing multi-option voting result for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = packed_reveal_args(&ctx.accounts.poll_acc);
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealMultiOption16ResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_multi_option_16_result")]
+    pub fn reveal_multi_option_16_result_callback(
+        ctx: Context<RevealMultiOption16ResultCallback>,
+        output: ComputationOutputs<RevealMultiOption16ResultOutput>,
+    ) -> Result<()> {
+        let (packed_counts, invalid, quorum_met, sufficient_participation) = match output {
+            ComputationOutputs::Success(RevealMultiOption16ResultOutput {
+                field_0: RevealMultiOption16ResultOutputStruct0 {
+                    field_0: packed_counts,
+                    field_1: invalid,
+                    field_2: quorum_met,
+                    field_3: sufficient_participation,
+                },
+            }) => (packed_counts, invalid, quorum_met, sufficient_participation),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        store_packed_reveal(
+            &mut ctx.accounts.poll_acc,
+            &packed_counts,
+            invalid,
+            quorum_met,
+            sufficient_participation,
+        )
+    }
+
+    pub fn init_reveal_multi_option_32_result_comp_def(ctx: Context<InitRevealMultiOption32ResultCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_multi_option_32_result_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals the vote counts of a multi-option poll with capacity 32.
+    ///
+    /// Same access rules as `reveal_multi_option_result`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_multi_option_32_result(
+        ctx: Context<RevealMultiOption32VotingResult>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.capacity == 32,
+            ErrorCode::PollCapacityMismatch
+        );
+
+        msg!("Revealing multi-option voting result for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = packed_reveal_args(&ctx.accounts.poll_acc);
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealMultiOption32ResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_multi_option_32_result")]
+    pub fn reveal_multi_option_32_result_callback(
+        ctx: Context<RevealMultiOption32ResultCallback>,
+        output: ComputationOutputs<RevealMultiOption32ResultOutput>,
+    ) -> Result<()> {
+        let (packed_counts, invalid, quorum_met, sufficient_participation) = match output {
+            ComputationOutputs::Success(RevealMultiOption32ResultOutput {
+                field_0: RevealMultiOption32ResultOutputStruct0 {
+                    field_0: packed_counts,
+                    field_1: invalid,
+                    field_2: quorum_met,
+                    field_3: sufficient_participation,
+                },
+            }) => (packed_counts, invalid, quorum_met, sufficient_participation),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        store_packed_reveal(
+            &mut ctx.accounts.poll_acc,
+            &packed_counts,
+            invalid,
+            quorum_met,
+            sufficient_participation,
+        )
+    }
+
+    // ==================== RANKED-CHOICE POLL INSTRUCTIONS ====================
+
+    pub fn init_ranked_vote_stats_comp_def(ctx: Context<InitRankedVoteStatsCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/init_ranked_vote_stats_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Creates a new ranked-choice poll (3-8 nominees) scored with Borda points.
+    ///
+    /// Voters rank their top three nominees; 1st, 2nd and 3rd place earn 10, 6 and 3 points.
+    /// The per-nominee scores are kept encrypted and accumulated through MPC until reveal.
+    ///
+    /// # Arguments
+    /// * `id` - Identifier for this poll, unique among polls created by the payer
+    /// * `question` - The poll question
+    /// * `options` - Array of 3-8 nominee strings
+    /// * `nonce` - Cryptographic nonce for initializing encrypted scores
+    /// * `starts_at` - Unix timestamp from which votes are accepted
+    /// * `ends_at` - Unix timestamp at which voting closes and reveal becomes possible
+    /// * `permissionless_reveal` - Whether anyone (not just the authority) may reveal after `ends_at`
+    pub fn create_ranked_poll(
+        ctx: Context<CreateRankedPoll>,
+        computation_offset: u64,
+        id: u32,
+        question: String,
+        options: Vec<String>,
+        nonce: u128,
+        starts_at: i64,
+        ends_at: i64,
+        permissionless_reveal: bool,
+    ) -> Result<()> {
+        require!(
+            options.len() >= 3 && options.len() <= 8,
+            ErrorCode::InvalidRankedOptionCount
+        );
+        require_valid_voting_window(starts_at, ends_at)?;
+
+        msg!("Creating a new ranked-choice poll with {} nominees", options.len());
+
+        // Initialize the poll account with the provided parameters
+        ctx.accounts.poll_acc.question = question;
+        ctx.accounts.poll_acc.options = options;
+        ctx.accounts.poll_acc.bump = ctx.bumps.poll_acc;
+        ctx.accounts.poll_acc.id = id;
+        ctx.accounts.poll_acc.authority = ctx.accounts.payer.key();
+        ctx.accounts.poll_acc.nonce = nonce;
+        ctx.accounts.poll_acc.vote_state = [[0; 32]; 10]; // 8 nominee scores + num_options + invalid
+        ctx.accounts.poll_acc.num_options = ctx.accounts.poll_acc.options.len() as u8;
+        ctx.accounts.poll_acc.starts_at = starts_at;
+        ctx.accounts.poll_acc.ends_at = ends_at;
+        ctx.accounts.poll_acc.permissionless_reveal = permissionless_reveal;
+        ctx.accounts.poll_acc.status = PollStatus::Draft;
+
+        let args = vec![
+            Argument::PlaintextU128(nonce),
+            Argument::PlaintextU8(ctx.accounts.poll_acc.num_options),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Initialize encrypted scores for all nominees through MPC
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitRankedVoteStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_ranked_vote_stats")]
+    pub fn init_ranked_vote_stats_callback(
+        ctx: Context<InitRankedVoteStatsCallback>,
+        output: ComputationOutputs<InitRankedVoteStatsOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitRankedVoteStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.status = poll_acc.status.transition_to(PollStatus::Active)?;
+        poll_acc.vote_state = o.ciphertexts;
+        poll_acc.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    pub fn init_vote_ranked_comp_def(ctx: Context<InitVoteRankedCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_ranked_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted ranked ballot to a ranked-choice poll.
+    ///
+    /// The voter's 1st, 2nd and 3rd choices are encrypted separately. The MPC computation
+    /// checks that they are distinct, existing nominees and adds their Borda points to the
+    /// running scores; malformed ballots earn no points. Individual rankings remain confidential.
+    /// Each wallet can vote once per poll; the first vote creates its `VoterRecord`.
+    ///
+    /// # Arguments
+    /// * `first_choice_encrypted` - Encrypted index of the 1st-ranked nominee
+    /// * `second_choice_encrypted` - Encrypted index of the 2nd-ranked nominee
+    /// * `third_choice_encrypted` - Encrypted index of the 3rd-ranked nominee
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    pub fn vote_ranked(
+        ctx: Context<VoteRanked>,
+        computation_offset: u64,
+        _id: u32,
+        first_choice_encrypted: [u8; 32],
+        second_choice_encrypted: [u8; 32],
+        third_choice_encrypted: [u8; 32],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+    ) -> Result<()> {
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+            Argument::EncryptedU8(first_choice_encrypted),
+            Argument::EncryptedU8(second_choice_encrypted),
+            Argument::EncryptedU8(third_choice_encrypted),
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 10, // 8 nominee scores + num_options + invalid, each stored as 32-byte ciphertext
+            ),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteRankedCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_ranked")]
+    pub fn vote_ranked_callback(
+        ctx: Context<VoteRankedCallback>,
+        output: ComputationOutputs<VoteRankedOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteRankedOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        ctx.accounts.poll_acc.vote_state = o.ciphertexts;
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_reveal_ranked_result_comp_def(ctx: Context<InitRevealRankedResultCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_ranked_result_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals the Borda scores of a ranked-choice poll.
+    ///
+    /// Only the poll authority can call this function, and only once voting has ended, to
+    /// decrypt and reveal the score of every nominee and the number of malformed ballots. Polls
+    /// created with `permissionless_reveal` can be revealed by anyone after `ends_at`. The reveal
+    /// can only be queued once.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_ranked_result(
+        ctx: Context<RevealRankedVotingResult>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        msg!("Revealing ranked-choice result for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 10, // 8 encrypted nominee scores + num_options + invalid, 32 bytes each
+            ),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealRankedResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_ranked_result")]
+    pub fn reveal_ranked_result_callback(
+        ctx: Context<RevealRankedResultCallback>,
+        output: ComputationOutputs<RevealRankedResultOutput>,
+    ) -> Result<()> {
+        let (scores, invalid_ballots) = match output {
+            ComputationOutputs::Success(RevealRankedResultOutput {
+                field_0: RevealRankedResultOutputStruct0 {
+                    field_0: scores,
+                    field_1: invalid_ballots,
+                },
+            }) => (scores, invalid_ballots),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.scores = scores;
+        poll_acc.invalid_ballots = invalid_ballots;
+
+        emit!(RevealRankedResultEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            scores,
+            invalid_ballots,
+        });
+
+        Ok(())
+    }
+
+    /// Closes a voter's receipt on a ranked-choice poll; see `close_voter_record`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID the receipt belongs to
+    pub fn close_ranked_voter_record(ctx: Context<CloseRankedVoterRecord>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.status.require_finalized()
+    }
+
+    /// Stops a ranked-choice poll from accepting further votes; see `close_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to close
+    pub fn close_ranked_poll(ctx: Context<UpdateRankedPollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().close(ctx.accounts.signer.key())
+    }
+
+    /// Cancels a ranked-choice poll; see `cancel_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to cancel
+    pub fn cancel_ranked_poll(ctx: Context<UpdateRankedPollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().cancel(ctx.accounts.signer.key())
+    }
+
+    // ==================== INSTANT-RUNOFF POLL INSTRUCTIONS ====================
+
+    pub fn init_irv_ballot_box_comp_def(ctx: Context<InitIrvBallotBoxCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/init_irv_ballot_box_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Creates a new instant-runoff poll (2-4 options) together with its ballot store.
+    ///
+    /// Unlike the tally-based polls, every encrypted ranking is kept in the ballot store so
+    /// elimination rounds can be run inside MPC at reveal time. The store holds at most
+    /// `MAX_IRV_BALLOTS` ballots, a hard cap: the poll is therefore restricted to an electorate
+    /// of at most that many wallets, fixed here, so every eligible voter is guaranteed a slot.
+    ///
+    /// # Arguments
+    /// * `id` - Identifier for this poll, unique among polls created by the payer
+    /// * `question` - The poll question
+    /// * `options` - Array of 2-4 option strings
+    /// * `electorate` - The 1-`MAX_IRV_BALLOTS` distinct wallets allowed to vote
+    /// * `nonce` - Cryptographic nonce for initializing the encrypted ballot box
+    /// * `starts_at` - Unix timestamp from which votes are accepted
+    /// * `ends_at` - Unix timestamp at which voting closes and reveal becomes possible
+    /// * `permissionless_reveal` - Whether anyone (not just the authority) may reveal after `ends_at`
+    pub fn create_irv_poll(
+        ctx: Context<CreateIrvPoll>,
+        computation_offset: u64,
+        id: u32,
+        question: String,
+        options: Vec<String>,
+        electorate: Vec<Pubkey>,
+        nonce: u128,
+        starts_at: i64,
+        ends_at: i64,
+        permissionless_reveal: bool,
+    ) -> Result<()> {
+        require!(options.len() >= 2 && options.len() <= 4, ErrorCode::InvalidOptionCount);
+        let mut distinct = electorate.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        require!(
+            !electorate.is_empty()
+                && electorate.len() <= MAX_IRV_BALLOTS as usize
+                && distinct.len() == electorate.len(),
+            ErrorCode::InvalidIrvElectorate
+        );
+        require_valid_voting_window(starts_at, ends_at)?;
+
+        msg!("Creating a new instant-runoff poll with {} options", options.len());
+
+        // Initialize the poll account with the provided parameters
+        ctx.accounts.poll_acc.question = question;
+        ctx.accounts.poll_acc.options = options;
+        ctx.accounts.poll_acc.bump = ctx.bumps.poll_acc;
+        ctx.accounts.poll_acc.id = id;
+        ctx.accounts.poll_acc.authority = ctx.accounts.payer.key();
+        ctx.accounts.poll_acc.num_options = ctx.accounts.poll_acc.options.len() as u8;
+        ctx.accounts.poll_acc.electorate = electorate;
+        ctx.accounts.poll_acc.starts_at = starts_at;
+        ctx.accounts.poll_acc.ends_at = ends_at;
+        ctx.accounts.poll_acc.permissionless_reveal = permissionless_reveal;
+        ctx.accounts.poll_acc.status = PollStatus::Draft;
+
+        ctx.accounts.ballot_store.bump = ctx.bumps.ballot_store;
+        ctx.accounts.ballot_store.poll = ctx.accounts.poll_acc.key();
+        ctx.accounts.ballot_store.nonce = nonce;
+        ctx.accounts.ballot_store.vote_state = [[0; 32]; 19]; // 16 ballots + num_ballots + num_options + invalid
+
+        let args = vec![
+            Argument::PlaintextU128(nonce),
+            Argument::PlaintextU8(ctx.accounts.poll_acc.num_options),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Initialize the empty encrypted ballot box through MPC
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitIrvBallotBoxCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: ctx.accounts.poll_acc.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.ballot_store.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_irv_ballot_box")]
+    pub fn init_irv_ballot_box_callback(
+        ctx: Context<InitIrvBallotBoxCallback>,
+        output: ComputationOutputs<InitIrvBallotBoxOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitIrvBallotBoxOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.status = poll_acc.status.transition_to(PollStatus::Active)?;
+        ctx.accounts.ballot_store.vote_state = o.ciphertexts;
+        ctx.accounts.ballot_store.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    pub fn init_vote_irv_comp_def(ctx: Context<InitVoteIrvCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_irv_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted ranking to an instant-runoff poll.
+    ///
+    /// The voter ranks every option from most to least preferred; slots beyond the poll's
+    /// option count are ignored. Malformed rankings are not stored but counted, and the count
+    /// is revealed with the winner. The ranking never leaves MPC. Only wallets in the poll's
+    /// electorate can vote, once each.
+    ///
+    /// # Arguments
+    /// * `ranks_encrypted` - Encrypted option indices, most preferred first
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    pub fn vote_irv(
+        ctx: Context<VoteIrv>,
+        computation_offset: u64,
+        _id: u32,
+        ranks_encrypted: [[u8; 32]; 4],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.poll_acc.electorate.contains(&ctx.accounts.payer.key()),
+            ErrorCode::NotInElectorate
+        );
+        // Cannot trigger while the electorate fits the store; kept as a guard for the circuit
+        require!(
+            ctx.accounts.poll_acc.ballot_count < MAX_IRV_BALLOTS,
+            ErrorCode::BallotStoreFull
+        );
+
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        // Counted at queue time so the ballot box can never overflow in MPC
+        ctx.accounts.poll_acc.ballot_count += 1;
+
+        let mut args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+        ];
+        for rank in ranks_encrypted {
+            args.push(Argument::EncryptedU8(rank));
+        }
+        args.push(Argument::PlaintextU128(ctx.accounts.ballot_store.nonce));
+        args.push(Argument::Account(
+            ctx.accounts.ballot_store.key(),
+            // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+            8 + 1,
+            32 * 19, // 16 packed ballots + num_ballots + num_options + invalid, 32 bytes each
+        ));
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteIrvCallback::callback_ix(&[
+                CallbackAccount {
+                    pubkey: poll,
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.ballot_store.key(),
+                    is_writable: true,
+                },
+                CallbackAccount {
+                    pubkey: ctx.accounts.voter_record.key(),
+                    is_writable: true,
+                },
+            ])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_irv")]
+    pub fn vote_irv_callback(
+        ctx: Context<VoteIrvCallback>,
+        output: ComputationOutputs<VoteIrvOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteIrvOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a ballot box that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            // The ballot never reached the box, so its slot is freed for another voter
+            ctx.accounts.poll_acc.ballot_count = ctx.accounts.poll_acc.ballot_count.saturating_sub(1);
+            return Ok(());
+        };
+
+        ctx.accounts.ballot_store.vote_state = o.ciphertexts;
+        ctx.accounts.ballot_store.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_reveal_irv_result_comp_def(ctx: Context<InitRevealIrvResultCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_irv_result_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Runs the instant-runoff count and reveals the winner of the poll.
+    ///
+    /// Only the poll authority can call this function, and only once voting has ended. Polls
+    /// created with `permissionless_reveal` can be revealed by anyone after `ends_at`. The MPC
+    /// computation runs all elimination rounds and reveals only the winning option, the number
+    /// of rounds it took and the number of malformed ballots; rankings and intermediate counts
+    /// stay encrypted.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_irv_result(
+        ctx: Context<RevealIrvVotingResult>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        msg!("Revealing instant-runoff result for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.ballot_store.nonce),
+            Argument::Account(
+                ctx.accounts.ballot_store.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 19, // 16 packed ballots + num_ballots + num_options + invalid, 32 bytes each
+            ),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealIrvResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_irv_result")]
+    pub fn reveal_irv_result_callback(
+        ctx: Context<RevealIrvResultCallback>,
+        output: ComputationOutputs<RevealIrvResultOutput>,
+    ) -> Result<()> {
+        let (winner, rounds, invalid) = match output {
+            ComputationOutputs::Success(RevealIrvResultOutput {
+                field_0: RevealIrvResultOutputStruct0 {
+                    field_0: winner,
+                    field_1: rounds,
+                    field_2: invalid,
+                },
+            }) => (winner, rounds, invalid),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.winner = winner;
+        poll_acc.rounds = rounds;
+        poll_acc.invalid_ballots = invalid;
+
+        emit!(RevealIrvResultEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            winner,
+            rounds,
+            invalid_count: invalid,
+        });
+
+        Ok(())
+    }
+
+    /// Closes a voter's receipt on an instant-runoff poll; see `close_voter_record`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID the receipt belongs to
+    pub fn close_irv_voter_record(ctx: Context<CloseIrvVoterRecord>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.status.require_finalized()
+    }
+
+    /// Stops an instant-runoff poll from accepting further votes; see `close_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to close
+    pub fn close_irv_poll(ctx: Context<UpdateIrvPollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().close(ctx.accounts.signer.key())
+    }
+
+    /// Cancels an instant-runoff poll; see `cancel_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to cancel
+    pub fn cancel_irv_poll(ctx: Context<UpdateIrvPollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().cancel(ctx.accounts.signer.key())
+    }
+
+    // ==================== SCORE POLL INSTRUCTIONS ====================
+
+    pub fn init_score_vote_stats_comp_def(ctx: Context<InitScoreVoteStatsCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/init_score_vote_stats_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Creates a new score poll (2-4 options) where voters rate every option from 0 to 10.
+    ///
+    /// The per-option sums and the number of ballots are kept encrypted and accumulated
+    /// through MPC until reveal.
+    ///
+    /// # Arguments
+    /// * `id` - Identifier for this poll, unique among polls created by the payer
+    /// * `question` - The poll question
+    /// * `options` - Array of 2-4 option strings
+    /// * `nonce` - Cryptographic nonce for initializing encrypted score sums
+    /// * `starts_at` - Unix timestamp from which votes are accepted
+    /// * `ends_at` - Unix timestamp at which voting closes and reveal becomes possible
+    /// * `permissionless_reveal` - Whether anyone (not just the authority) may reveal after `ends_at`
+    pub fn create_score_poll(
+        ctx: Context<CreateScorePoll>,
+        computation_offset: u64,
+        id: u32,
+        question: String,
+        options: Vec<String>,
+        nonce: u128,
+        starts_at: i64,
+        ends_at: i64,
+        permissionless_reveal: bool,
+    ) -> Result<()> {
+        require!(options.len() >= 2 && options.len() <= 4, ErrorCode::InvalidOptionCount);
+        require_valid_voting_window(starts_at, ends_at)?;
+
+        msg!("Creating a new score poll with {} options", options.len());
+
+        // Initialize the poll account with the provided parameters
+        ctx.accounts.poll_acc.question = question;
+        ctx.accounts.poll_acc.options = options;
+        ctx.accounts.poll_acc.bump = ctx.bumps.poll_acc;
+        ctx.accounts.poll_acc.id = id;
+        ctx.accounts.poll_acc.authority = ctx.accounts.payer.key();
+        ctx.accounts.poll_acc.nonce = nonce;
+        ctx.accounts.poll_acc.vote_state = [[0; 32]; 6]; // 4 score sums + voter_count + num_options
+        ctx.accounts.poll_acc.num_options = ctx.accounts.poll_acc.options.len() as u8;
+        ctx.accounts.poll_acc.starts_at = starts_at;
+        ctx.accounts.poll_acc.ends_at = ends_at;
+        ctx.accounts.poll_acc.permissionless_reveal = permissionless_reveal;
+        ctx.accounts.poll_acc.status = PollStatus::Draft;
+
+        let args = vec![
+            Argument::PlaintextU128(nonce),
+            Argument::PlaintextU8(ctx.accounts.poll_acc.num_options),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        // Initialize encrypted score sums for all options through MPC
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitScoreVoteStatsCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_score_vote_stats")]
+    pub fn init_score_vote_stats_callback(
+        ctx: Context<InitScoreVoteStatsCallback>,
+        output: ComputationOutputs<InitScoreVoteStatsOutput>,
+    ) -> Result<()> {
+        let o = match output {
+            ComputationOutputs::Success(InitScoreVoteStatsOutput { field_0 }) => field_0,
+            _ => return Err(ErrorCode::AbortedComputation.into()),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.status = poll_acc.status.transition_to(PollStatus::Active)?;
+        poll_acc.vote_state = o.ciphertexts;
+        poll_acc.nonce = o.nonce;
+
+        Ok(())
+    }
+
+    pub fn init_vote_score_comp_def(ctx: Context<InitVoteScoreCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/vote_score_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Submits an encrypted score ballot to a score poll.
+    ///
+    /// The voter rates each option from 0 to 10; every rating is encrypted separately and
+    /// added to the option's running sum through MPC. Ratings above 10 are clamped inside
+    /// MPC. Each wallet can vote once per poll; the first vote creates its `VoterRecord`.
+    ///
+    /// # Arguments
+    /// * `scores_encrypted` - Encrypted rating (0-10) for each of the 4 option slots
+    /// * `vote_encryption_pubkey` - Voter's public key for encryption
+    /// * `vote_nonce` - Cryptographic nonce for the vote encryption
+    pub fn vote_score(
+        ctx: Context<VoteScore>,
+        computation_offset: u64,
+        _id: u32,
+        scores_encrypted: [[u8; 32]; 4],
+        vote_encryption_pubkey: [u8; 32],
+        vote_nonce: u128,
+    ) -> Result<()> {
+        let poll = ctx.accounts.poll_acc.key();
+        record_vote(
+            ctx.accounts.poll_acc.lifecycle(),
+            &mut ctx.accounts.voter_record,
+            ctx.bumps.voter_record,
+            poll,
+            ctx.accounts.payer.key(),
+        )?;
+
+        let mut args = vec![
+            Argument::ArcisPubkey(vote_encryption_pubkey),
+            Argument::PlaintextU128(vote_nonce),
+        ];
+        for score in scores_encrypted {
+            args.push(Argument::EncryptedU8(score));
+        }
+        args.push(Argument::PlaintextU128(ctx.accounts.poll_acc.nonce));
+        args.push(Argument::Account(
+            ctx.accounts.poll_acc.key(),
+            // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+            8 + 1,
+            32 * 6, // 4 score sums + voter_count + num_options, each stored as 32-byte ciphertext
+        ));
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![VoteScoreCallback::callback_ix(&vote_callback_accounts(
+                poll,
+                ctx.accounts.voter_record.key(),
+            ))],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "vote_score")]
+    pub fn vote_score_callback(
+        ctx: Context<VoteScoreCallback>,
+        output: ComputationOutputs<VoteScoreOutput>,
+    ) -> Result<()> {
+        let output = match output {
+            ComputationOutputs::Success(VoteScoreOutput { field_0 }) => Some(field_0),
+            _ => None,
+        };
+
+        // Output computed against a vote_state that has since been superseded is dropped
+        let Some(o) = ctx.accounts.poll_acc.lifecycle().settle_vote(
+            &mut ctx.accounts.voter_record,
+            output,
+        ) else {
+            return Ok(());
+        };
+
+        ctx.accounts.poll_acc.vote_state = o.ciphertexts;
+        ctx.accounts.poll_acc.nonce = o.nonce;
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+
+        emit!(VoteEvent {
+            poll: ctx.accounts.poll_acc.key(),
+            authority: ctx.accounts.poll_acc.authority,
+            id: ctx.accounts.poll_acc.id,
+            timestamp: current_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn init_reveal_score_result_comp_def(ctx: Context<InitRevealScoreResultCompDef>) -> Result<()> {
+        init_comp_def(
+            ctx.accounts,
+            true,
+            0,
+            Some(CircuitSource::OffChain(OffChainCircuitSource {
+                source: "https://raw.githubusercontent.com/koon-ran/Arcanecast/main/voting/build/reveal_score_result_testnet.arcis".to_string(),
+                hash: [0; 32],
+            })),
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// Reveals the score sums and ballot count of a score poll.
+    ///
+    /// Only the poll authority can call this function, and only once voting has ended. Polls
+    /// created with `permissionless_reveal` can be revealed by anyone after `ends_at`. Averages
+    /// are derived off-chain as `score_totals[i] / voter_count`. The reveal can only be queued once.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to reveal results for
+    pub fn reveal_score_result(
+        ctx: Context<RevealScoreVotingResult>,
+        computation_offset: u64,
+        id: u32,
+    ) -> Result<()> {
+        msg!("Revealing score result for poll with id {}", id);
+
+        ctx.accounts.poll_acc.lifecycle().begin_reveal(ctx.accounts.payer.key())?;
+
+        let args = vec![
+            Argument::PlaintextU128(ctx.accounts.poll_acc.nonce),
+            Argument::Account(
+                ctx.accounts.poll_acc.key(),
+                // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+                8 + 1,
+                32 * 6, // 4 score sums + voter_count + num_options, 32 bytes each
+            ),
+        ];
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealScoreResultCallback::callback_ix(&[CallbackAccount {
+                pubkey: ctx.accounts.poll_acc.key(),
+                is_writable: true,
+            }])],
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_score_result")]
+    pub fn reveal_score_result_callback(
+        ctx: Context<RevealScoreResultCallback>,
+        output: ComputationOutputs<RevealScoreResultOutput>,
+    ) -> Result<()> {
+        let (totals, voter_count) = match output {
+            ComputationOutputs::Success(RevealScoreResultOutput {
+                field_0: RevealScoreResultOutputStruct0 {
+                    field_0: totals,
+                    field_1: voter_count,
+                },
+            }) => (totals, voter_count),
+            _ => return ctx.accounts.poll_acc.lifecycle().abort_reveal(),
+        };
+
+        let poll_acc = &mut ctx.accounts.poll_acc;
+        poll_acc.lifecycle().finish_reveal()?;
+        poll_acc.score_totals = totals;
+        poll_acc.voter_count = voter_count;
+
+        emit!(RevealScoreResultEvent {
+            poll: poll_acc.key(),
+            authority: poll_acc.authority,
+            id: poll_acc.id,
+            score_totals: totals,
+            voter_count,
+        });
+
+        Ok(())
+    }
+
+    /// Closes a voter's receipt on a score poll; see `close_voter_record`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID the receipt belongs to
+    pub fn close_score_voter_record(ctx: Context<CloseScoreVoterRecord>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.status.require_finalized()
+    }
+
+    /// Stops a score poll from accepting further votes; see `close_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to close
+    pub fn close_score_poll(ctx: Context<UpdateScorePollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().close(ctx.accounts.signer.key())
+    }
+
+    /// Cancels a score poll; see `cancel_poll`.
+    ///
+    /// # Arguments
+    /// * `id` - The poll ID to cancel
+    pub fn cancel_score_poll(ctx: Context<UpdateScorePollStatus>, _id: u32) -> Result<()> {
+        ctx.accounts.poll_acc.lifecycle().cancel(ctx.accounts.signer.key())
+    }
+}
+
+#[queue_computation_accounts("init_vote_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct CreateNewPoll<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PollAccount::INIT_SPACE,
+        seeds = [b"poll", payer.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+}
+
+#[callback_accounts("init_vote_stats")]
+#[derive(Accounts)]
+pub struct InitVoteStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// CHECK: poll_acc, checked by the callback account key passed in queue_computation
+    #[account(mut)]
+    pub poll_acc: Account<'info, PollAccount>,
+}
+
+#[init_computation_definition_accounts("init_vote_stats", payer)]
+#[derive(Accounts)]
+pub struct InitVoteStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct Vote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote")]
+#[derive(Accounts)]
+pub struct VoteCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, PollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote", payer)]
+#[derive(Accounts)]
+pub struct InitVoteCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_weighted", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteWeighted<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_WEIGHTED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_weighted")]
+#[derive(Accounts)]
+pub struct VoteWeightedCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_WEIGHTED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, PollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_weighted", payer)]
+#[derive(Accounts)]
+pub struct InitVoteWeightedCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_tristate", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteTristate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_TRISTATE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_tristate")]
+#[derive(Accounts)]
+pub struct VoteTristateCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_TRISTATE)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, PollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_tristate", payer)]
+#[derive(Accounts)]
+pub struct InitVoteTristateCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealVotingResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+}
+
+#[callback_accounts("reveal_result")]
+#[derive(Accounts)]
+pub struct RevealResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, PollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_threshold_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealThresholdVotingResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_THRESHOLD)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+}
+
+#[callback_accounts("reveal_threshold_result")]
+#[derive(Accounts)]
+pub struct RevealThresholdResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_THRESHOLD)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, PollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_threshold_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealThresholdResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct CloseVoterRecord<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"voter", poll_acc.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct UpdatePollStatus<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, PollAccount>,
+}
+
+// ==================== MULTI-OPTION POLL ACCOUNT STRUCTS ====================
+
+#[queue_computation_accounts("init_multi_option_vote_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct CreateMultiOptionPoll<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MULTI_OPTION_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MultiOptionPollAccount::INIT_SPACE,
+        seeds = [b"multi_poll", payer.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[callback_accounts("init_multi_option_vote_stats")]
+#[derive(Accounts)]
+pub struct InitMultiOptionVoteStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MULTI_OPTION_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// CHECK: poll_acc, checked by the callback account key passed in queue_computation
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[init_computation_definition_accounts("init_multi_option_vote_stats", payer)]
+#[derive(Accounts)]
+pub struct InitMultiOptionVoteStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_multi_option", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteMultiOption<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_multi_option")]
+#[derive(Accounts)]
+pub struct VoteMultiOptionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_multi_option", payer)]
+#[derive(Accounts)]
+pub struct InitVoteMultiOptionCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_multi_option_weighted", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteMultiOptionWeighted<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_WEIGHTED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_multi_option_weighted")]
+#[derive(Accounts)]
+pub struct VoteMultiOptionWeightedCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_WEIGHTED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_multi_option_weighted", payer)]
+#[derive(Accounts)]
+pub struct InitVoteMultiOptionWeightedCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_approval", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteApproval<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_APPROVAL)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_approval")]
+#[derive(Accounts)]
+pub struct VoteApprovalCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_APPROVAL)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_approval", payer)]
+#[derive(Accounts)]
+pub struct InitVoteApprovalCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_quadratic", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteQuadratic<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_QUADRATIC)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_quadratic")]
+#[derive(Accounts)]
+pub struct VoteQuadraticCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_QUADRATIC)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_quadratic", payer)]
+#[derive(Accounts)]
+pub struct InitVoteQuadraticCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_multi_option_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealMultiOptionVotingResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[callback_accounts("reveal_multi_option_result")]
+#[derive(Accounts)]
+pub struct RevealMultiOptionResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_multi_option_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealMultiOptionResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_multi_option_winner", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealMultiOptionWinner<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_WINNER)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[callback_accounts("reveal_multi_option_winner")]
+#[derive(Accounts)]
+pub struct RevealMultiOptionWinnerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_WINNER)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_multi_option_winner", payer)]
+#[derive(Accounts)]
+pub struct InitRevealMultiOptionWinnerCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_multi_option_result_dp", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealMultiOptionResultDp<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_DP)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[callback_accounts("reveal_multi_option_result_dp")]
+#[derive(Accounts)]
+pub struct RevealMultiOptionResultDpCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_DP)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_multi_option_result_dp", payer)]
+#[derive(Accounts)]
+pub struct InitRevealMultiOptionResultDpCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct CloseMultiOptionVoterRecord<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"voter", poll_acc.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct UpdateMultiOptionPollStatus<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+// ==================== LARGE MULTI-OPTION POLL ACCOUNT STRUCTS ====================
+
+#[queue_computation_accounts("vote_multi_option_8", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteMultiOption8<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_8)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_multi_option_8")]
+#[derive(Accounts)]
+pub struct VoteMultiOption8Callback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_8)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_multi_option_8", payer)]
+#[derive(Accounts)]
+pub struct InitVoteMultiOption8CompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_multi_option_16", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteMultiOption16<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_16)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_multi_option_16")]
+#[derive(Accounts)]
+pub struct VoteMultiOption16Callback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_16)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_multi_option_16", payer)]
+#[derive(Accounts)]
+pub struct InitVoteMultiOption16CompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_multi_option_32", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteMultiOption32<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_32)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    /// Voter's token account for the poll's required mint; only needed on token-gated polls
+    pub voter_token_account: Option<Account<'info, TokenAccount>>,
+    /// Voter's token account holding an NFT of the poll's collection; only needed on NFT-gated polls
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+    /// Metaplex metadata account of that NFT
+    pub nft_metadata: Option<Account<'info, MetadataAccount>>,
+}
+
+#[callback_accounts("vote_multi_option_32")]
+#[derive(Accounts)]
+pub struct VoteMultiOption32Callback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION_32)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_multi_option_32", payer)]
+#[derive(Accounts)]
+pub struct InitVoteMultiOption32CompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_multi_option_8_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealMultiOption8VotingResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_8)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[callback_accounts("reveal_multi_option_8_result")]
+#[derive(Accounts)]
+pub struct RevealMultiOption8ResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_8)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_multi_option_8_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealMultiOption8ResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_multi_option_16_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealMultiOption16VotingResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_16)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[callback_accounts("reveal_multi_option_16_result")]
+#[derive(Accounts)]
+pub struct RevealMultiOption16ResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_16)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_multi_option_16_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealMultiOption16ResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_multi_option_32_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealMultiOption32VotingResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_32)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"multi_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[callback_accounts("reveal_multi_option_32_result")]
+#[derive(Accounts)]
+pub struct RevealMultiOption32ResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION_32)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_multi_option_32_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealMultiOption32ResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// ==================== RANKED-CHOICE POLL ACCOUNT STRUCTS ====================
+
+#[queue_computation_accounts("init_ranked_vote_stats", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct CreateRankedPoll<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_RANKED_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RankedPollAccount::INIT_SPACE,
+        seeds = [b"ranked_poll", payer.key().as_ref(), id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+}
+
+#[callback_accounts("init_ranked_vote_stats")]
+#[derive(Accounts)]
+pub struct InitRankedVoteStatsCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_RANKED_VOTE_STATS)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// CHECK: poll_acc, checked by the callback account key passed in queue_computation
+    #[account(mut)]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+}
+
+#[init_computation_definition_accounts("init_ranked_vote_stats", payer)]
+#[derive(Accounts)]
+pub struct InitRankedVoteStatsCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("vote_ranked", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, _id: u32)]
+pub struct VoteRanked<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_RANKED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"ranked_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[callback_accounts("vote_ranked")]
+#[derive(Accounts)]
+pub struct VoteRankedCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_RANKED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[init_computation_definition_accounts("vote_ranked", payer)]
+#[derive(Accounts)]
+pub struct InitVoteRankedCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("reveal_ranked_result", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct RevealRankedVotingResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_RANKED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
+        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+    )]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(
+        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+    )]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        seeds = [b"ranked_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump
+    )]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+}
+
+#[callback_accounts("reveal_ranked_result")]
+#[derive(Accounts)]
+pub struct RevealRankedResultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_RANKED)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+}
+
+#[init_computation_definition_accounts("reveal_ranked_result", payer)]
+#[derive(Accounts)]
+pub struct InitRevealRankedResultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct CloseRankedVoterRecord<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"ranked_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"voter", poll_acc.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct UpdateRankedPollStatus<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"ranked_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, RankedPollAccount>,
+}
+
+// ==================== INSTANT-RUNOFF POLL ACCOUNT STRUCTS ====================
+
+#[queue_computation_accounts("init_irv_ballot_box", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, id: u32)]
+pub struct CreateIrvPoll<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    #[account(
+        address = derive_mxe_pda!()
+    )]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!()
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!()
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_IRV_BALLOT_BOX)
+    )]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(
+        mut,
         address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
     )]
     pub pool_account: Account<'info, FeePool>,
@@ -549,19 +6013,27 @@ pub struct CreateNewPoll<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + PollAccount::INIT_SPACE,
-        seeds = [b"poll", id.to_le_bytes().as_ref()],
+        space = 8 + IrvPollAccount::INIT_SPACE,
+        seeds = [b"irv_poll", payer.key().as_ref(), id.to_le_bytes().as_ref()],
         bump,
     )]
-    pub poll_acc: Account<'info, PollAccount>,
+    pub poll_acc: Account<'info, IrvPollAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + IrvBallotStore::INIT_SPACE,
+        seeds = [b"irv_ballots", poll_acc.key().as_ref()],
+        bump,
+    )]
+    pub ballot_store: Account<'info, IrvBallotStore>,
 }
 
-#[callback_accounts("init_vote_stats")]
+#[callback_accounts("init_irv_ballot_box")]
 #[derive(Accounts)]
-pub struct InitVoteStatsCallback<'info> {
+pub struct InitIrvBallotBoxCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_VOTE_STATS)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_IRV_BALLOT_BOX)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
@@ -569,12 +6041,14 @@ pub struct InitVoteStatsCallback<'info> {
     pub instructions_sysvar: AccountInfo<'info>,
     /// CHECK: poll_acc, checked by the callback account key passed in queue_computation
     #[account(mut)]
-    pub poll_acc: Account<'info, PollAccount>,
+    pub poll_acc: Account<'info, IrvPollAccount>,
+    #[account(mut)]
+    pub ballot_store: Account<'info, IrvBallotStore>,
 }
 
-#[init_computation_definition_accounts("init_vote_stats", payer)]
+#[init_computation_definition_accounts("init_irv_ballot_box", payer)]
 #[derive(Accounts)]
-pub struct InitVoteStatsCompDef<'info> {
+pub struct InitIrvBallotBoxCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -590,10 +6064,10 @@ pub struct InitVoteStatsCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("vote", payer)]
+#[queue_computation_accounts("vote_irv", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, _id: u32)]
-pub struct Vote<'info> {
+pub struct VoteIrv<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -628,7 +6102,7 @@ pub struct Vote<'info> {
     /// CHECK: computation_account, checked by the arcium program.
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_IRV)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -648,30 +6122,52 @@ pub struct Vote<'info> {
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        seeds = [b"poll", _id.to_le_bytes().as_ref()],
+        mut,
+        seeds = [b"irv_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
         bump = poll_acc.bump,
     )]
-    pub poll_acc: Account<'info, PollAccount>,
+    pub poll_acc: Account<'info, IrvPollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+    #[account(
+        mut,
+        seeds = [b"irv_ballots", poll_acc.key().as_ref()],
+        bump = ballot_store.bump,
+    )]
+    pub ballot_store: Account<'info, IrvBallotStore>,
 }
 
-#[callback_accounts("vote")]
+#[callback_accounts("vote_irv")]
 #[derive(Accounts)]
-pub struct VoteCallback<'info> {
+pub struct VoteIrvCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_IRV)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: instructions_sysvar, checked by the account constraint
     pub instructions_sysvar: AccountInfo<'info>,
     #[account(mut)]
-    pub poll_acc: Account<'info, PollAccount>,
+    pub poll_acc: Account<'info, IrvPollAccount>,
+    #[account(mut)]
+    pub ballot_store: Account<'info, IrvBallotStore>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
 }
 
-#[init_computation_definition_accounts("vote", payer)]
+#[init_computation_definition_accounts("vote_irv", payer)]
 #[derive(Accounts)]
-pub struct InitVoteCompDef<'info> {
+pub struct InitVoteIrvCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -687,10 +6183,10 @@ pub struct InitVoteCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("reveal_result", payer)]
+#[queue_computation_accounts("reveal_irv_result", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, id: u32)]
-pub struct RevealVotingResult<'info> {
+pub struct RevealIrvVotingResult<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -725,7 +6221,7 @@ pub struct RevealVotingResult<'info> {
     /// CHECK: computation_account, checked by the arcium program.
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_IRV)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -745,28 +6241,36 @@ pub struct RevealVotingResult<'info> {
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        seeds = [b"poll", id.to_le_bytes().as_ref()],
+        mut,
+        seeds = [b"irv_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
         bump = poll_acc.bump
     )]
-    pub poll_acc: Account<'info, PollAccount>,
+    pub poll_acc: Account<'info, IrvPollAccount>,
+    #[account(
+        seeds = [b"irv_ballots", poll_acc.key().as_ref()],
+        bump = ballot_store.bump,
+    )]
+    pub ballot_store: Account<'info, IrvBallotStore>,
 }
 
-#[callback_accounts("reveal_result")]
+#[callback_accounts("reveal_irv_result")]
 #[derive(Accounts)]
-pub struct RevealResultCallback<'info> {
+pub struct RevealIrvResultCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_IRV)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: instructions_sysvar, checked by the account constraint
     pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, IrvPollAccount>,
 }
 
-#[init_computation_definition_accounts("reveal_result", payer)]
+#[init_computation_definition_accounts("reveal_irv_result", payer)]
 #[derive(Accounts)]
-pub struct InitRevealResultCompDef<'info> {
+pub struct InitRevealIrvResultCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -782,12 +6286,43 @@ pub struct InitRevealResultCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-// ==================== MULTI-OPTION POLL ACCOUNT STRUCTS ====================
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct CloseIrvVoterRecord<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"irv_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, IrvPollAccount>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"voter", poll_acc.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+}
 
-#[queue_computation_accounts("init_multi_option_vote_stats", payer)]
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct UpdateIrvPollStatus<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"irv_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, IrvPollAccount>,
+}
+
+// ==================== SCORE POLL ACCOUNT STRUCTS ====================
+
+#[queue_computation_accounts("init_score_vote_stats", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, id: u32)]
-pub struct CreateMultiOptionPoll<'info> {
+pub struct CreateScorePoll<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -822,7 +6357,7 @@ pub struct CreateMultiOptionPoll<'info> {
     /// CHECK: computation_account, checked by the arcium program.
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MULTI_OPTION_VOTE_STATS)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_SCORE_VOTE_STATS)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -844,19 +6379,19 @@ pub struct CreateMultiOptionPoll<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + MultiOptionPollAccount::INIT_SPACE,
-        seeds = [b"multi_poll", id.to_le_bytes().as_ref()],
+        space = 8 + ScorePollAccount::INIT_SPACE,
+        seeds = [b"score_poll", payer.key().as_ref(), id.to_le_bytes().as_ref()],
         bump,
     )]
-    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    pub poll_acc: Account<'info, ScorePollAccount>,
 }
 
-#[callback_accounts("init_multi_option_vote_stats")]
+#[callback_accounts("init_score_vote_stats")]
 #[derive(Accounts)]
-pub struct InitMultiOptionVoteStatsCallback<'info> {
+pub struct InitScoreVoteStatsCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MULTI_OPTION_VOTE_STATS)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_SCORE_VOTE_STATS)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
@@ -864,12 +6399,12 @@ pub struct InitMultiOptionVoteStatsCallback<'info> {
     pub instructions_sysvar: AccountInfo<'info>,
     /// CHECK: poll_acc, checked by the callback account key passed in queue_computation
     #[account(mut)]
-    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    pub poll_acc: Account<'info, ScorePollAccount>,
 }
 
-#[init_computation_definition_accounts("init_multi_option_vote_stats", payer)]
+#[init_computation_definition_accounts("init_score_vote_stats", payer)]
 #[derive(Accounts)]
-pub struct InitMultiOptionVoteStatsCompDef<'info> {
+pub struct InitScoreVoteStatsCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -885,10 +6420,10 @@ pub struct InitMultiOptionVoteStatsCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("vote_multi_option", payer)]
+#[queue_computation_accounts("vote_score", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, _id: u32)]
-pub struct VoteMultiOption<'info> {
+pub struct VoteScore<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -923,7 +6458,7 @@ pub struct VoteMultiOption<'info> {
     /// CHECK: computation_account, checked by the arcium program.
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_SCORE)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -943,30 +6478,44 @@ pub struct VoteMultiOption<'info> {
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        seeds = [b"multi_poll", _id.to_le_bytes().as_ref()],
+        mut,
+        seeds = [b"score_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
         bump = poll_acc.bump,
     )]
-    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    pub poll_acc: Account<'info, ScorePollAccount>,
+    // init_if_needed so a repeat vote reaches the handler and fails with AlreadyVoted
+    // instead of a generic "account already in use" error from the system program
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [b"voter", poll_acc.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
 }
 
-#[callback_accounts("vote_multi_option")]
+#[callback_accounts("vote_score")]
 #[derive(Accounts)]
-pub struct VoteMultiOptionCallback<'info> {
+pub struct VoteScoreCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_MULTI_OPTION)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_SCORE)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: instructions_sysvar, checked by the account constraint
     pub instructions_sysvar: AccountInfo<'info>,
     #[account(mut)]
-    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    pub poll_acc: Account<'info, ScorePollAccount>,
+    /// Receipt of the voter whose ballot this is; cleared if the ballot is not counted
+    #[account(mut)]
+    pub voter_record: Account<'info, VoterRecord>,
 }
 
-#[init_computation_definition_accounts("vote_multi_option", payer)]
+#[init_computation_definition_accounts("vote_score", payer)]
 #[derive(Accounts)]
-pub struct InitVoteMultiOptionCompDef<'info> {
+pub struct InitVoteScoreCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -982,10 +6531,10 @@ pub struct InitVoteMultiOptionCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("reveal_multi_option_result", payer)]
+#[queue_computation_accounts("reveal_score_result", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64, id: u32)]
-pub struct RevealMultiOptionVotingResult<'info> {
+pub struct RevealScoreVotingResult<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1020,7 +6569,7 @@ pub struct RevealMultiOptionVotingResult<'info> {
     /// CHECK: computation_account, checked by the arcium program.
     pub computation_account: UncheckedAccount<'info>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_SCORE)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
@@ -1040,28 +6589,31 @@ pub struct RevealMultiOptionVotingResult<'info> {
     pub system_program: Program<'info, System>,
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        seeds = [b"multi_poll", id.to_le_bytes().as_ref()],
+        mut,
+        seeds = [b"score_poll", poll_acc.authority.as_ref(), id.to_le_bytes().as_ref()],
         bump = poll_acc.bump
     )]
-    pub poll_acc: Account<'info, MultiOptionPollAccount>,
+    pub poll_acc: Account<'info, ScorePollAccount>,
 }
 
-#[callback_accounts("reveal_multi_option_result")]
+#[callback_accounts("reveal_score_result")]
 #[derive(Accounts)]
-pub struct RevealMultiOptionResultCallback<'info> {
+pub struct RevealScoreResultCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
     #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_MULTI_OPTION)
+        address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_SCORE)
     )]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
     /// CHECK: instructions_sysvar, checked by the account constraint
     pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub poll_acc: Account<'info, ScorePollAccount>,
 }
 
-#[init_computation_definition_accounts("reveal_multi_option_result", payer)]
+#[init_computation_definition_accounts("reveal_score_result", payer)]
 #[derive(Accounts)]
-pub struct InitRevealMultiOptionResultCompDef<'info> {
+pub struct InitRevealScoreResultCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -1077,38 +6629,271 @@ pub struct InitRevealMultiOptionResultCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Represents a confidential poll with encrypted vote tallies.
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct CloseScoreVoterRecord<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"score_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, ScorePollAccount>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"voter", poll_acc.key().as_ref(), voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Account<'info, VoterRecord>,
+}
+
+#[derive(Accounts)]
+#[instruction(_id: u32)]
+pub struct UpdateScorePollStatus<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"score_poll", poll_acc.authority.as_ref(), _id.to_le_bytes().as_ref()],
+        bump = poll_acc.bump,
+    )]
+    pub poll_acc: Account<'info, ScorePollAccount>,
+}
+
+/// Represents a confidential poll with encrypted vote tallies.
+#[account]
+#[derive(InitSpace)]
+pub struct PollAccount {
+    /// PDA bump seed
+    pub bump: u8,
+    /// Encrypted vote counters: [yes_count, no_count, abstain_count, total] as 32-byte ciphertexts
+    pub vote_state: [[u8; 32]; 4],
+    /// Identifier for this poll, unique per authority (PDA seeds: prefix, authority, id)
+    pub id: u32,
+    /// Public key of the poll creator (only they can reveal results, unless `permissionless_reveal`)
+    pub authority: Pubkey,
+    /// Cryptographic nonce for the encrypted vote counters
+    pub nonce: u128,
+    /// The poll question (max 50 characters)
+    #[max_len(50)]
+    pub question: String,
+    /// Unix timestamp from which votes are accepted
+    pub starts_at: i64,
+    /// Unix timestamp at which voting closes; results can be revealed from then on
+    pub ends_at: i64,
+    /// Whether anyone may reveal results after `ends_at`, not just the authority
+    pub permissionless_reveal: bool,
+    /// Which wallets may vote
+    pub eligibility: Eligibility,
+    /// Whether ballots are weighted by the voter's `token_gate` balance instead of counting 1
+    pub weighted: bool,
+    /// Minimum number of ballots for the poll to be decided; checked inside MPC at reveal
+    pub min_turnout: u64,
+    /// Required yes share for the poll to pass; `None` means a simple majority
+    pub pass_threshold: Option<PassThreshold>,
+    /// Current lifecycle stage of the poll
+    pub status: PollStatus,
+    /// Version of the encrypted state, bumped whenever it is replaced or voting stops; a vote
+    /// callback only applies output computed against the current version
+    pub state_version: u64,
+    /// Revealed outcome (valid once `status` is `Revealed`)
+    pub result: PollOutcome,
+    /// Slot at which the result was revealed
+    pub revealed_slot: u64,
+    /// Unix timestamp at which the result was revealed
+    pub revealed_at: i64,
+}
+
+/// Represents a multi-option poll (2-32 options) for DAO voting.
+#[account]
+#[derive(InitSpace)]
+pub struct MultiOptionPollAccount {
+    /// PDA bump seed
+    pub bump: u8,
+    /// Encrypted vote counters as 32-byte ciphertexts: [counter1, ..., counter4, num_options,
+    /// invalid, total, counter5, ..., counter8]. Polls with 2-4 options use the first 7 with one
+    /// counter per option; larger polls pack four counters per word and use the first 7 entries
+    /// for capacity 8 or 16 and all 11 for capacity 32 (see `MULTI_OPTION_PREFIX_LEN`)
+    pub vote_state: [[u8; 32]; 11],
+    /// Identifier for this poll, unique per authority (PDA seeds: prefix, authority, id)
+    pub id: u32,
+    /// Public key of the poll creator (only they can reveal results, unless `permissionless_reveal`)
+    pub authority: Pubkey,
+    /// Cryptographic nonce for the encrypted vote counters
+    pub nonce: u128,
+    /// The poll question (max 100 characters)
+    #[max_len(100)]
+    pub question: String,
+    /// Poll options (2-32 strings, max 50 characters each)
+    #[max_len(32, 50)]
+    pub options: Vec<String>,
+    /// Number of options (2-32)
+    pub num_options: u8,
+    /// Maximum number of options of the poll's circuit family: 4, 8, 16 or 32
+    pub capacity: u8,
+    /// How voters fill in their ballot
+    pub ballot_type: BallotType,
+    /// Credits each voter may spend on `BallotType::Quadratic` polls (0 otherwise)
+    pub credit_budget: u64,
+    /// What the reveal discloses
+    pub reveal_mode: RevealMode,
+    /// Privacy parameter of `RevealMode::NoisyCounts` polls, in thousandths of epsilon (0 otherwise)
+    pub dp_epsilon_millis: u32,
+    /// Unix timestamp from which votes are accepted
+    pub starts_at: i64,
+    /// Unix timestamp at which voting closes; results can be revealed from then on
+    pub ends_at: i64,
+    /// Whether anyone may reveal results after `ends_at`, not just the authority
+    pub permissionless_reveal: bool,
+    /// Which wallets may vote
+    pub eligibility: Eligibility,
+    /// Whether ballots are weighted by the voter's `token_gate` balance instead of counting 1
+    pub weighted: bool,
+    /// Minimum number of ballots for the counts to be disclosed; checked inside MPC at reveal
+    pub min_turnout: u64,
+    /// k-anonymity threshold for full-count reveals; below it only insufficient participation is disclosed
+    pub min_ballots: u64,
+    /// Current lifecycle stage of the poll
+    pub status: PollStatus,
+    /// Version of the encrypted state, bumped whenever it is replaced or voting stops; a vote
+    /// callback only applies output computed against the current version
+    pub state_version: u64,
+    /// Revealed vote counts per option (valid once `status` is `Revealed`)
+    pub option_counts: [u64; 32],
+    /// Revealed number of out-of-range ballots that were not counted
+    pub invalid_ballots: u64,
+    /// Revealed winning option index (`RevealMode::WinnerOnly` polls)
+    pub winner: u8,
+    /// Whether the top count was shared by several options (`RevealMode::WinnerOnly` polls)
+    pub is_tie: bool,
+    /// Coarse lead of the winner: 0 = tie, 1 = under 10%, 2 = 10-25%, 3 = 25% or more
+    pub margin_bucket: u8,
+    /// Whether at least `min_turnout` ballots were cast; if not, the revealed results are zeroed
+    pub quorum_met: bool,
+    /// Whether at least `min_ballots` ballots were cast; if not, the revealed counts are zeroed
+    pub sufficient_participation: bool,
+    /// Slot at which the result was revealed
+    pub revealed_slot: u64,
+    /// Unix timestamp at which the result was revealed
+    pub revealed_at: i64,
+}
+
+/// Represents a ranked-choice poll (3-8 nominees) scored with Borda points.
 #[account]
 #[derive(InitSpace)]
-pub struct PollAccount {
+pub struct RankedPollAccount {
     /// PDA bump seed
     pub bump: u8,
-    /// Encrypted vote counters: [yes_count, no_count] as 32-byte ciphertexts
-    pub vote_state: [[u8; 32]; 2],
-    /// Unique identifier for this poll
+    /// Encrypted Borda scores: [score1, ..., score8, num_options, invalid] as 32-byte ciphertexts
+    pub vote_state: [[u8; 32]; 10],
+    /// Identifier for this poll, unique per authority (PDA seeds: prefix, authority, id)
     pub id: u32,
-    /// Public key of the poll creator (only they can reveal results)
+    /// Public key of the poll creator (only they can reveal results, unless `permissionless_reveal`)
     pub authority: Pubkey,
-    /// Cryptographic nonce for the encrypted vote counters
+    /// Cryptographic nonce for the encrypted scores
     pub nonce: u128,
-    /// The poll question (max 50 characters)
-    #[max_len(50)]
+    /// The poll question (max 100 characters)
+    #[max_len(100)]
     pub question: String,
+    /// Nominees (3-8 strings, max 50 characters each)
+    #[max_len(8, 50)]
+    pub options: Vec<String>,
+    /// Number of nominees (3-8)
+    pub num_options: u8,
+    /// Unix timestamp from which votes are accepted
+    pub starts_at: i64,
+    /// Unix timestamp at which voting closes; results can be revealed from then on
+    pub ends_at: i64,
+    /// Whether anyone may reveal results after `ends_at`, not just the authority
+    pub permissionless_reveal: bool,
+    /// Current lifecycle stage of the poll
+    pub status: PollStatus,
+    /// Version of the encrypted state, bumped whenever it is replaced or voting stops; a vote
+    /// callback only applies output computed against the current version
+    pub state_version: u64,
+    /// Revealed Borda score per nominee (valid once `status` is `Revealed`)
+    pub scores: [u64; 8],
+    /// Revealed number of malformed ballots, which earned no points
+    pub invalid_ballots: u64,
+    /// Slot at which the result was revealed
+    pub revealed_slot: u64,
+    /// Unix timestamp at which the result was revealed
+    pub revealed_at: i64,
 }
 
-/// Represents a multi-option poll (2-4 options) for DAO voting.
+/// Represents an instant-runoff poll (2-4 options). Its encrypted ballots live in an `IrvBallotStore`.
 #[account]
 #[derive(InitSpace)]
-pub struct MultiOptionPollAccount {
+pub struct IrvPollAccount {
     /// PDA bump seed
     pub bump: u8,
-    /// Encrypted vote counters: [option1, option2, option3, option4, num_options] as 32-byte ciphertexts
-    pub vote_state: [[u8; 32]; 5],
-    /// Unique identifier for this poll
+    /// Identifier for this poll, unique per authority (PDA seeds: prefix, authority, id)
     pub id: u32,
-    /// Public key of the poll creator (only they can reveal results)
+    /// Public key of the poll creator (only they can reveal results, unless `permissionless_reveal`)
     pub authority: Pubkey,
-    /// Cryptographic nonce for the encrypted vote counters
+    /// The poll question (max 100 characters)
+    #[max_len(100)]
+    pub question: String,
+    /// Poll options (2-4 strings, max 50 characters each)
+    #[max_len(4, 50)]
+    pub options: Vec<String>,
+    /// Number of options (2-4)
+    pub num_options: u8,
+    /// Wallets allowed to vote (at most `MAX_IRV_BALLOTS`, so each has a slot in the store)
+    #[max_len(16)]
+    pub electorate: Vec<Pubkey>,
+    /// Unix timestamp from which votes are accepted
+    pub starts_at: i64,
+    /// Unix timestamp at which voting closes; results can be revealed from then on
+    pub ends_at: i64,
+    /// Whether anyone may reveal results after `ends_at`, not just the authority
+    pub permissionless_reveal: bool,
+    /// Current lifecycle stage of the poll
+    pub status: PollStatus,
+    /// Version of the encrypted state, bumped whenever it is replaced or voting stops; a vote
+    /// callback only applies output computed against the current version
+    pub state_version: u64,
+    /// Number of ballots queued so far (capped at `MAX_IRV_BALLOTS`)
+    pub ballot_count: u8,
+    /// Revealed winning option index (valid once `status` is `Revealed`)
+    pub winner: u8,
+    /// Number of elimination rounds the count took; 0 means no valid ballots were cast
+    pub rounds: u8,
+    /// Revealed number of malformed rankings that were not counted
+    pub invalid_ballots: u8,
+    /// Slot at which the result was revealed
+    pub revealed_slot: u64,
+    /// Unix timestamp at which the result was revealed
+    pub revealed_at: i64,
+}
+
+/// Holds the encrypted rankings of an instant-runoff poll.
+#[account]
+#[derive(InitSpace)]
+pub struct IrvBallotStore {
+    /// PDA bump seed
+    pub bump: u8,
+    /// Encrypted ballot box: [ballot1, ..., ballot16, num_ballots, num_options, invalid] as 32-byte ciphertexts
+    pub vote_state: [[u8; 32]; 19],
+    /// Poll this store belongs to (PDA seeds: "irv_ballots", poll)
+    pub poll: Pubkey,
+    /// Cryptographic nonce for the encrypted ballot box
+    pub nonce: u128,
+}
+
+/// Represents a score poll (2-4 options) where each option is rated 0-10.
+#[account]
+#[derive(InitSpace)]
+pub struct ScorePollAccount {
+    /// PDA bump seed
+    pub bump: u8,
+    /// Encrypted score sums: [total1, total2, total3, total4, voter_count, num_options] as 32-byte ciphertexts
+    pub vote_state: [[u8; 32]; 6],
+    /// Identifier for this poll, unique per authority (PDA seeds: prefix, authority, id)
+    pub id: u32,
+    /// Public key of the poll creator (only they can reveal results, unless `permissionless_reveal`)
+    pub authority: Pubkey,
+    /// Cryptographic nonce for the encrypted score sums
     pub nonce: u128,
     /// The poll question (max 100 characters)
     #[max_len(100)]
@@ -1118,6 +6903,324 @@ pub struct MultiOptionPollAccount {
     pub options: Vec<String>,
     /// Number of options (2-4)
     pub num_options: u8,
+    /// Unix timestamp from which votes are accepted
+    pub starts_at: i64,
+    /// Unix timestamp at which voting closes; results can be revealed from then on
+    pub ends_at: i64,
+    /// Whether anyone may reveal results after `ends_at`, not just the authority
+    pub permissionless_reveal: bool,
+    /// Current lifecycle stage of the poll
+    pub status: PollStatus,
+    /// Version of the encrypted state, bumped whenever it is replaced or voting stops; a vote
+    /// callback only applies output computed against the current version
+    pub state_version: u64,
+    /// Revealed sum of ratings per option (valid once `status` is `Revealed`)
+    pub score_totals: [u64; 4],
+    /// Revealed number of ballots counted
+    pub voter_count: u64,
+    /// Slot at which the result was revealed
+    pub revealed_slot: u64,
+    /// Unix timestamp at which the result was revealed
+    pub revealed_at: i64,
+}
+
+/// Required share of yes votes among yes/no votes, e.g. 2/3 for a supermajority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct PassThreshold {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl PassThreshold {
+    /// Checks that the threshold is a fraction in (0, 1] with a bounded denominator.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.numerator > 0
+                && self.numerator <= self.denominator
+                && self.denominator <= MAX_THRESHOLD_DENOMINATOR,
+            ErrorCode::InvalidThreshold
+        );
+        Ok(())
+    }
+}
+
+/// SPL token holding a wallet needs to vote on a token-gated poll.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct TokenGate {
+    pub mint: Pubkey,
+    pub min_balance: u64,
+}
+
+/// Which wallets may vote on a poll. Every gate that is set must pass.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct Eligibility {
+    /// SPL token holding required to vote; `None` if any wallet may vote
+    pub token_gate: Option<TokenGate>,
+    /// Merkle root of the wallets allowed to vote (see `allowlist`); `None` if any wallet may vote
+    pub allowlist_root: Option<[u8; 32]>,
+    /// Verified Metaplex collection whose NFT holders may vote; `None` if any wallet may vote
+    pub nft_collection: Option<Pubkey>,
+}
+
+/// What a voter presents to prove eligibility; accounts for unset gates may be omitted.
+pub struct VoterCredentials<'a> {
+    pub voter: Pubkey,
+    pub token_account: Option<&'a TokenAccount>,
+    pub allowlist_proof: &'a [[u8; 32]],
+    pub nft_token_account: Option<&'a TokenAccount>,
+    pub nft_metadata: Option<&'a MetadataAccount>,
+}
+
+impl Eligibility {
+    /// Checks `credentials` against every gate of the poll.
+    pub fn require_can_vote(&self, credentials: &VoterCredentials<'_>) -> Result<()> {
+        require_token_gate(&self.token_gate, credentials.token_account, credentials.voter)?;
+        require_allowlisted(&self.allowlist_root, credentials.allowlist_proof, credentials.voter)?;
+        require_collection_member(
+            &self.nft_collection,
+            credentials.nft_token_account,
+            credentials.nft_metadata,
+            credentials.voter,
+        )
+    }
+}
+
+/// Outcome of a yes/no poll, decoded from the code revealed by the `reveal_result` circuit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PollOutcome {
+    No,
+    Yes,
+    Tie,
+    QuorumNotMet,
+}
+
+impl PollOutcome {
+    /// Maps the circuit's outcome code (0 = No, 1 = Yes, 2 = Tie, 3 = QuorumNotMet) to an outcome,
+    /// or `None` for any other code.
+    pub fn from_code(code: u8) -> Option<PollOutcome> {
+        match code {
+            0 => Some(PollOutcome::No),
+            1 => Some(PollOutcome::Yes),
+            2 => Some(PollOutcome::Tie),
+            3 => Some(PollOutcome::QuorumNotMet),
+            _ => None,
+        }
+    }
+}
+
+/// How voters fill in a multi-option ballot.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum BallotType {
+    /// Exactly one option per voter (`vote_multi_option`)
+    SingleChoice,
+    /// Any subset of the options per voter (`vote_approval`)
+    Approval,
+    /// Votes spread across options at a cost of votes squared, within a credit budget (`vote_quadratic`)
+    Quadratic,
+}
+
+/// What the reveal of a multi-option poll discloses.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RevealMode {
+    /// Every option's count (`reveal_multi_option_result`)
+    FullCounts,
+    /// Only the winner, a tie flag and a margin bucket (`reveal_multi_option_winner`)
+    WinnerOnly,
+    /// Every option's count with differentially private noise (`reveal_multi_option_result_dp`)
+    NoisyCounts,
+}
+
+/// Lifecycle of a poll. Transitions are validated by `PollStatus::transition_to`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum PollStatus {
+    /// Created, waiting for the encrypted counters to be initialized
+    Draft,
+    /// Accepting votes within the voting window
+    Active,
+    /// No longer accepting votes, waiting to be revealed
+    Closed,
+    /// Reveal computation queued, waiting for its callback
+    Revealing,
+    /// Result revealed and stored on the poll account
+    Revealed,
+    /// Cancelled by the authority; will never be revealed
+    Cancelled,
+}
+
+impl PollStatus {
+    /// Returns `next` if moving from `self` to `next` is a legal transition.
+    ///
+    /// An `Active` poll may go straight to `Revealing` once its voting window is over, and a
+    /// `Revealing` poll drops back to `Closed` if the reveal computation aborts.
+    pub fn transition_to(self, next: PollStatus) -> Result<PollStatus> {
+        use PollStatus::*;
+        match (self, next) {
+            (Draft, Active)
+            | (Active, Closed)
+            | (Active | Closed, Revealing)
+            | (Revealing, Revealed | Closed)
+            | (Draft | Active | Closed, Cancelled) => Ok(next),
+            (Cancelled, _) => err!(ErrorCode::PollCancelled),
+            (Revealed, _) => err!(ErrorCode::PollAlreadyRevealed),
+            (Revealing, _) => err!(ErrorCode::RevealAlreadyQueued),
+            _ => err!(ErrorCode::InvalidStatusTransition),
+        }
+    }
+
+    /// Fails unless the poll is revealed or cancelled, after which receipts may be closed.
+    pub fn require_finalized(self) -> Result<()> {
+        require!(
+            matches!(self, PollStatus::Revealed | PollStatus::Cancelled),
+            ErrorCode::PollNotFinalized
+        );
+        Ok(())
+    }
+}
+
+/// Mutable view of the lifecycle fields every poll account shares, so the status, state version
+/// and reveal rules are implemented once for all poll types.
+pub struct Lifecycle<'a> {
+    pub authority: Pubkey,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub permissionless_reveal: bool,
+    pub status: &'a mut PollStatus,
+    pub state_version: &'a mut u64,
+    pub revealed_slot: &'a mut u64,
+    pub revealed_at: &'a mut i64,
+}
+
+macro_rules! impl_lifecycle {
+    ($($poll:ty),+ $(,)?) => {$(
+        impl $poll {
+            /// Lifecycle view over this poll's status, state version and reveal fields.
+            pub fn lifecycle(&mut self) -> Lifecycle<'_> {
+                Lifecycle {
+                    authority: self.authority,
+                    starts_at: self.starts_at,
+                    ends_at: self.ends_at,
+                    permissionless_reveal: self.permissionless_reveal,
+                    status: &mut self.status,
+                    state_version: &mut self.state_version,
+                    revealed_slot: &mut self.revealed_slot,
+                    revealed_at: &mut self.revealed_at,
+                }
+            }
+        }
+    )+};
+}
+
+impl_lifecycle!(
+    PollAccount,
+    MultiOptionPollAccount,
+    RankedPollAccount,
+    IrvPollAccount,
+    ScorePollAccount,
+);
+
+impl Lifecycle<'_> {
+    /// Settles the vote whose receipt is `voter_record` when its callback runs, returning the
+    /// output to store only if the computation succeeded against the current state version.
+    ///
+    /// Applying an output bumps the version, so any other vote computed against the same state
+    /// is dropped when it calls back. A stale or aborted vote leaves the state untouched and
+    /// clears the voter's receipt, so the callback can succeed without counting the ballot and
+    /// the wallet may vote again.
+    pub fn settle_vote<T>(
+        &mut self,
+        voter_record: &mut VoterRecord,
+        output: Option<T>,
+    ) -> Option<T> {
+        if voter_record.state_version != *self.state_version {
+            msg!("Vote computed against superseded state, receipt cleared");
+            voter_record.has_voted = false;
+            return None;
+        }
+        if output.is_none() {
+            msg!("Vote computation aborted, receipt cleared");
+            voter_record.has_voted = false;
+            return None;
+        }
+        *self.state_version += 1;
+        output
+    }
+
+    /// Moves the poll to `Revealing` on behalf of `caller`, who must be the authority unless
+    /// reveals are permissionless.
+    pub fn begin_reveal(&mut self, caller: Pubkey) -> Result<()> {
+        require!(
+            self.permissionless_reveal || caller == self.authority,
+            ErrorCode::InvalidAuthority
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= self.ends_at,
+            ErrorCode::VotingStillOpen
+        );
+        // Votes still in flight were not read by the reveal, so their callbacks must drop them
+        *self.state_version += 1;
+
+        // An active poll past ends_at is closed implicitly; this also ensures the reveal runs once
+        *self.status = self.status.transition_to(PollStatus::Revealing)?;
+        Ok(())
+    }
+
+    /// Marks the poll revealed, once the reveal callback has stored the result.
+    pub fn finish_reveal(&mut self) -> Result<()> {
+        let clock = Clock::get()?;
+        *self.status = self.status.transition_to(PollStatus::Revealed)?;
+        *self.revealed_slot = clock.slot;
+        *self.revealed_at = clock.unix_timestamp;
+        Ok(())
+    }
+
+    /// Returns the poll to `Closed` after its reveal computation aborted, so the reveal can be
+    /// queued again instead of staying stuck.
+    pub fn abort_reveal(&mut self) -> Result<()> {
+        *self.status = self.status.transition_to(PollStatus::Closed)?;
+        msg!("Reveal computation aborted, poll returned to Closed");
+        Ok(())
+    }
+
+    /// Stops the poll from accepting further votes. The authority can close it at any time;
+    /// once `ends_at` has passed anyone can.
+    pub fn close(&mut self, caller: Pubkey) -> Result<()> {
+        require!(
+            caller == self.authority || Clock::get()?.unix_timestamp >= self.ends_at,
+            ErrorCode::InvalidAuthority
+        );
+        // Votes still in flight are dropped when they call back, since voting has stopped
+        *self.state_version += 1;
+        *self.status = self.status.transition_to(PollStatus::Closed)?;
+        Ok(())
+    }
+
+    /// Cancels the poll so it can never be revealed. Only the authority can cancel.
+    pub fn cancel(&mut self, caller: Pubkey) -> Result<()> {
+        require!(caller == self.authority, ErrorCode::InvalidAuthority);
+        // Votes still in flight are dropped when they call back
+        *self.state_version += 1;
+        *self.status = self.status.transition_to(PollStatus::Cancelled)?;
+        Ok(())
+    }
+}
+
+/// Receipt marking that a wallet has voted on a poll.
+///
+/// Seeded by the poll account and the voter, so each wallet gets exactly one per poll.
+#[account]
+#[derive(InitSpace)]
+pub struct VoterRecord {
+    /// PDA bump seed
+    pub bump: u8,
+    /// The poll account this receipt belongs to
+    pub poll: Pubkey,
+    /// The wallet that cast the vote
+    pub voter: Pubkey,
+    /// Whether this wallet has a ballot counted or in flight; cleared again if the ballot's
+    /// computation aborts or is superseded, so the wallet can vote again
+    pub has_voted: bool,
+    /// Poll state version the in-flight ballot was computed against
+    pub state_version: u64,
 }
 
 #[error_code]
@@ -1128,24 +7231,708 @@ pub enum ErrorCode {
     AbortedComputation,
     #[msg("Cluster not set")]
     ClusterNotSet,
-    #[msg("Invalid option count - must be between 2 and 4")]
+    #[msg("Invalid option count - score and instant-runoff polls take 2 to 4 options")]
     InvalidOptionCount,
+    #[msg("This wallet has already voted on this poll")]
+    AlreadyVoted,
+    #[msg("The poll is not accepting votes")]
+    PollNotActive,
+    #[msg("The poll has not been revealed or cancelled yet")]
+    PollNotFinalized,
+    #[msg("Invalid voting window - ends_at must be after starts_at and in the future")]
+    InvalidVotingWindow,
+    #[msg("Voting has not started yet")]
+    VotingNotStarted,
+    #[msg("Voting has ended")]
+    VotingEnded,
+    #[msg("Voting is still open - results cannot be revealed before ends_at")]
+    VotingStillOpen,
+    #[msg("The reveal for this poll has already been queued")]
+    RevealAlreadyQueued,
+    #[msg("The poll has been cancelled")]
+    PollCancelled,
+    #[msg("The poll has already been revealed")]
+    PollAlreadyRevealed,
+    #[msg("Illegal poll status transition")]
+    InvalidStatusTransition,
+    #[msg("Invalid nominee count - must be between 3 and 8")]
+    InvalidRankedOptionCount,
+    #[msg("The poll's ballot store is full")]
+    BallotStoreFull,
+    #[msg("Invalid electorate - instant-runoff polls need 1-16 distinct wallets")]
+    InvalidIrvElectorate,
+    #[msg("This vote instruction does not match the poll's ballot type")]
+    WrongBallotType,
+    #[msg("Invalid option count - multi-option polls must have between 2 and 32 options")]
+    InvalidMultiOptionCount,
+    #[msg("Polls with more than 4 options only support unweighted single-choice ballots with full-count reveals")]
+    UnsupportedLargePoll,
+    #[msg("This instruction does not match the poll's option capacity")]
+    PollCapacityMismatch,
+    #[msg("This reveal instruction does not match the poll's reveal mode")]
+    WrongRevealMode,
+    #[msg("Invalid pass threshold - must be a fraction in (0, 1] with a denominator of at most 10000")]
+    InvalidThreshold,
+    #[msg("Invalid epsilon - noisy-count polls need 250-5000 thousandths, other reveal modes 0")]
+    InvalidEpsilon,
+    #[msg("This wallet does not hold the token balance required to vote on this poll")]
+    IneligibleVoter,
+    #[msg("This wallet is not on the poll's allowlist")]
+    NotOnAllowlist,
+    #[msg("This wallet does not hold an NFT from the poll's verified collection")]
+    NotCollectionMember,
+    #[msg("Weighted polls need a token gate, single-choice ballots and an exact reveal mode")]
+    InvalidWeightedPoll,
+    #[msg("This vote instruction does not match whether the poll is weighted")]
+    WrongVoteWeighting,
+    #[msg("Invalid credit budget - quadratic polls need a positive budget, other ballot types 0")]
+    InvalidCreditBudget,
+    #[msg("This wallet is not in the instant-runoff poll's electorate")]
+    NotInElectorate,
+}
+
+/// Checks the voting window passed at poll creation.
+fn require_valid_voting_window(starts_at: i64, ends_at: i64) -> Result<()> {
+    require!(
+        ends_at > starts_at && ends_at > Clock::get()?.unix_timestamp,
+        ErrorCode::InvalidVotingWindow
+    );
+    Ok(())
+}
+
+/// Checks that the current time falls within `[starts_at, ends_at)`.
+fn require_voting_open(starts_at: i64, ends_at: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= starts_at, ErrorCode::VotingNotStarted);
+    require!(now < ends_at, ErrorCode::VotingEnded);
+    Ok(())
+}
+
+/// The fields of a voter's token account that the eligibility gates look at.
+#[derive(Clone, Copy)]
+struct TokenHolding {
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+}
+
+impl From<&TokenAccount> for TokenHolding {
+    fn from(token_account: &TokenAccount) -> Self {
+        Self {
+            mint: token_account.mint,
+            owner: token_account.owner,
+            amount: token_account.amount,
+        }
+    }
+}
+
+impl TokenGate {
+    /// Whether `holding` belongs to `voter` and holds enough of the gate's mint.
+    fn admits(&self, holding: TokenHolding, voter: Pubkey) -> bool {
+        holding.mint == self.mint && holding.owner == voter && holding.amount >= self.min_balance
+    }
+}
+
+/// Checks that `voter` holds the poll's required token balance; ungated polls always pass.
+fn require_token_gate(
+    token_gate: &Option<TokenGate>,
+    voter_token_account: Option<&TokenAccount>,
+    voter: Pubkey,
+) -> Result<()> {
+    if let Some(gate) = token_gate {
+        let token_account = voter_token_account.ok_or(ErrorCode::IneligibleVoter)?;
+        require!(
+            gate.admits(token_account.into(), voter),
+            ErrorCode::IneligibleVoter
+        );
+    }
+    Ok(())
+}
+
+/// Checks `proof` against the poll's allowlist root; polls without an allowlist always pass.
+fn require_allowlisted(allowlist_root: &Option<[u8; 32]>, proof: &[[u8; 32]], voter: Pubkey) -> Result<()> {
+    if let Some(root) = allowlist_root {
+        require!(allowlist::verify(root, &voter, proof), ErrorCode::NotOnAllowlist);
+    }
+    Ok(())
+}
+
+/// Checks that `voter` holds an NFT verified as part of the poll's collection; ungated polls
+/// always pass. The metadata account is owned by the Token Metadata program, so its `mint`
+/// field ties it to the NFT held in the token account.
+fn require_collection_member(
+    nft_collection: &Option<Pubkey>,
+    nft_token_account: Option<&TokenAccount>,
+    nft_metadata: Option<&MetadataAccount>,
+    voter: Pubkey,
+) -> Result<()> {
+    if let Some(collection) = nft_collection {
+        let (token_account, metadata) = nft_token_account
+            .zip(nft_metadata)
+            .ok_or(ErrorCode::NotCollectionMember)?;
+        require!(
+            admits_collection_member(
+                collection,
+                token_account.into(),
+                metadata.mint,
+                metadata.collection.as_ref(),
+                voter,
+            ),
+            ErrorCode::NotCollectionMember
+        );
+    }
+    Ok(())
+}
+
+/// Whether `holding` is an NFT of `voter` whose metadata (`metadata_mint`, `metadata_collection`)
+/// belongs to the same mint and is verified as part of `collection`.
+fn admits_collection_member(
+    collection: &Pubkey,
+    holding: TokenHolding,
+    metadata_mint: Pubkey,
+    metadata_collection: Option<&Collection>,
+    voter: Pubkey,
+) -> bool {
+    let verified_member = metadata_collection.is_some_and(|c| c.verified && c.key == *collection);
+    holding.owner == voter
+        && holding.amount >= 1
+        && metadata_mint == holding.mint
+        && verified_member
+}
+
+/// Capacity of the circuit family serving a multi-option poll with `num_options` options.
+fn multi_option_capacity(num_options: usize) -> Option<u8> {
+    match num_options {
+        2..=4 => Some(4),
+        5..=8 => Some(8),
+        9..=16 => Some(16),
+        17..=32 => Some(32),
+        _ => None,
+    }
+}
+
+/// Ciphertexts of `vote_state` read by the circuits of a packed poll: the shared prefix, plus
+/// the four extra counter words of capacity-32 polls.
+fn packed_state_len(capacity: u8) -> u32 {
+    if capacity == 32 {
+        MULTI_OPTION_STATE_LEN as u32
+    } else {
+        MULTI_OPTION_PREFIX_LEN as u32
+    }
+}
+
+/// Arguments of the `vote_multi_option_*` circuit matching the poll's capacity.
+fn packed_vote_args(
+    poll_acc: &Account<MultiOptionPollAccount>,
+    selected_option_encrypted: [u8; 32],
+    vote_encryption_pubkey: [u8; 32],
+    vote_nonce: u128,
+) -> Vec<Argument> {
+    vec![
+        Argument::ArcisPubkey(vote_encryption_pubkey),
+        Argument::PlaintextU128(vote_nonce),
+        Argument::EncryptedU8(selected_option_encrypted),
+        Argument::PlaintextU128(poll_acc.nonce),
+        Argument::Account(
+            poll_acc.key(),
+            // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+            8 + 1,
+            32 * packed_state_len(poll_acc.capacity),
+        ),
+    ]
+}
+
+/// Arguments of the `reveal_multi_option_*_result` circuit matching the poll's capacity.
+fn packed_reveal_args(poll_acc: &Account<MultiOptionPollAccount>) -> Vec<Argument> {
+    vec![
+        Argument::PlaintextU128(poll_acc.nonce),
+        Argument::Account(
+            poll_acc.key(),
+            // Offset calculation: 8 bytes (discriminator) + 1 byte (bump)
+            8 + 1,
+            32 * packed_state_len(poll_acc.capacity),
+        ),
+        Argument::PlaintextU64(poll_acc.min_turnout),
+        Argument::PlaintextU64(poll_acc.min_ballots),
+    ]
+}
+
+/// Stores the output of a `reveal_multi_option_*_result` circuit on the poll and emits it.
+fn store_packed_reveal(
+    poll_acc: &mut Account<MultiOptionPollAccount>,
+    packed_counts: &[u128],
+    invalid: u64,
+    quorum_met: bool,
+    sufficient_participation: bool,
+) -> Result<()> {
+    poll_acc.lifecycle().finish_reveal()?;
+    unpack_option_counts(packed_counts, &mut poll_acc.option_counts);
+    poll_acc.invalid_ballots = invalid;
+    poll_acc.quorum_met = quorum_met;
+    poll_acc.sufficient_participation = sufficient_participation;
+
+    emit!(RevealLargeMultiOptionResultEvent {
+        poll: poll_acc.key(),
+        authority: poll_acc.authority,
+        id: poll_acc.id,
+        option_counts: poll_acc.option_counts[..poll_acc.num_options as usize].to_vec(),
+        invalid_count: invalid,
+        quorum_met,
+        sufficient_participation,
+    });
+
+    Ok(())
+}
+
+/// Unpacks 32-bit counters stored four to a word (lane `i % 4` of word `i / 4`).
+fn unpack_option_counts(packed_counts: &[u128], option_counts: &mut [u64]) {
+    for (i, count) in option_counts.iter_mut().enumerate() {
+        *count = packed_counts
+            .get(i / 4)
+            .map_or(0, |word| ((word >> (32 * (i % 4))) & 0xFFFF_FFFF) as u64);
+    }
+}
+
+/// Converts epsilon (in thousandths) to e^-epsilon as a 16-bit fixed-point fraction, the
+/// per-step continue probability of the geometric noise in `reveal_multi_option_result_dp`.
+/// The result is rounded down, so the noise realizes an epsilon at most 0.0014 above the
+/// requested one over the accepted range.
+fn dp_continue_probability(epsilon_millis: u32) -> u128 {
+    // e^-0.001 in 32-bit fixed point, raised to `epsilon_millis` by square-and-multiply
+    const E_NEG_MILLI_Q32: u128 = 4_290_674_475;
+    let mut result: u128 = 1 << 32;
+    let mut base = E_NEG_MILLI_Q32;
+    let mut exponent = epsilon_millis;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = (result * base) >> 32;
+        }
+        base = (base * base) >> 32;
+        exponent >>= 1;
+    }
+    result >> 16
+}
+
+/// Records `voter`'s receipt, stamped with the poll's current state version.
+///
+/// The receipt is written before queuing so a second vote from this wallet is rejected. The
+/// vote reads the encrypted state now and overwrites it in the callback, which only happens
+/// if no other vote has replaced that state in between (see `Lifecycle::settle_vote`).
+fn record_vote(
+    lifecycle: Lifecycle<'_>,
+    voter_record: &mut VoterRecord,
+    bump: u8,
+    poll: Pubkey,
+    voter: Pubkey,
+) -> Result<()> {
+    require!(
+        *lifecycle.status == PollStatus::Active,
+        ErrorCode::PollNotActive
+    );
+    require_voting_open(lifecycle.starts_at, lifecycle.ends_at)?;
+    require!(!voter_record.has_voted, ErrorCode::AlreadyVoted);
+
+    voter_record.bump = bump;
+    voter_record.poll = poll;
+    voter_record.voter = voter;
+    voter_record.has_voted = true;
+    voter_record.state_version = *lifecycle.state_version;
+    Ok(())
+}
+
+/// Accounts passed to a vote callback: the poll it writes and the voter's receipt, which
+/// records the state version the ballot was computed against and is cleared if the ballot is
+/// not counted.
+fn vote_callback_accounts(poll: Pubkey, voter_record: Pubkey) -> [CallbackAccount; 2] {
+    [
+        CallbackAccount {
+            pubkey: poll,
+            is_writable: true,
+        },
+        CallbackAccount {
+            pubkey: voter_record,
+            is_writable: true,
+        },
+    ]
 }
 
 #[event]
 pub struct VoteEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct RevealResultEvent {
-    pub output: bool,
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
+    pub output: PollOutcome,
 }
 
 #[event]
 pub struct RevealMultiOptionResultEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
     pub option_1_count: u64,
     pub option_2_count: u64,
     pub option_3_count: u64,
     pub option_4_count: u64,
-}
\ No newline at end of file
+    pub invalid_count: u64,
+    pub quorum_met: bool,
+    pub sufficient_participation: bool,
+}
+
+#[event]
+pub struct RevealRankedResultEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
+    pub scores: [u64; 8],
+    /// Malformed ballots, which earned no points
+    pub invalid_ballots: u64,
+}
+
+#[event]
+pub struct RevealIrvResultEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
+    pub winner: u8,
+    pub rounds: u8,
+    pub invalid_count: u8,
+}
+
+#[event]
+pub struct RevealScoreResultEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
+    pub score_totals: [u64; 4],
+    pub voter_count: u64,
+}
+
+#[event]
+pub struct RevealLargeMultiOptionResultEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
+    pub option_counts: Vec<u64>,
+    pub invalid_count: u64,
+    pub quorum_met: bool,
+    pub sufficient_participation: bool,
+}
+
+#[event]
+pub struct RevealMultiOptionWinnerEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
+    pub winner: u8,
+    pub is_tie: bool,
+    pub margin_bucket: u8,
+    pub quorum_met: bool,
+}
+
+/// Noisy counts released by `reveal_multi_option_result_dp`.
+///
+/// The guarantees below use add/remove-one-ballot adjacency: two polls are neighbours if one
+/// has a single extra ballot. Replacing one ballot with another is two such steps and costs
+/// twice the budget.
+#[event]
+pub struct RevealMultiOptionDpResultEvent {
+    /// Poll account the event refers to
+    pub poll: Pubkey,
+    /// Poll creator; together with `id` this derives the poll PDA
+    pub authority: Pubkey,
+    /// Poll identifier, unique per authority
+    pub id: u32,
+    /// Counts with discrete Laplace noise of scale 1/epsilon, each draw truncated at 64 and the
+    /// result clamped at 0
+    pub noisy_counts: [u64; 4],
+    /// Per-count privacy parameter, in thousandths of epsilon; the truncation adds a per-count
+    /// delta of at most 2 * e^(-64 * epsilon) * (1 + e^epsilon)
+    pub epsilon_millis: u32,
+    /// Total privacy budget spent by this release for any single ballot, in thousandths:
+    /// epsilon for single-choice polls, epsilon times the option count for approval polls.
+    /// Delta composes the same way
+    pub privacy_budget_millis: u32,
+    /// Whether the poll reached its minimum turnout; the counts are 0 otherwise
+    pub quorum_met: bool,
+    /// Whether the poll reached its k-anonymity threshold; the counts are 0 otherwise
+    pub sufficient_participation: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holding(mint: Pubkey, owner: Pubkey, amount: u64) -> TokenHolding {
+        TokenHolding {
+            mint,
+            owner,
+            amount,
+        }
+    }
+
+    #[test]
+    fn token_gate_admits_a_sufficient_balance() {
+        let gate = TokenGate {
+            mint: Pubkey::new_unique(),
+            min_balance: 100,
+        };
+        let voter = Pubkey::new_unique();
+        assert!(gate.admits(holding(gate.mint, voter, 100), voter));
+        assert!(gate.admits(holding(gate.mint, voter, u64::MAX), voter));
+    }
+
+    #[test]
+    fn token_gate_rejects_the_wrong_mint() {
+        let gate = TokenGate {
+            mint: Pubkey::new_unique(),
+            min_balance: 100,
+        };
+        let voter = Pubkey::new_unique();
+        assert!(!gate.admits(holding(Pubkey::new_unique(), voter, 1_000), voter));
+    }
+
+    #[test]
+    fn token_gate_rejects_a_balance_below_the_minimum() {
+        let gate = TokenGate {
+            mint: Pubkey::new_unique(),
+            min_balance: 100,
+        };
+        let voter = Pubkey::new_unique();
+        assert!(!gate.admits(holding(gate.mint, voter, 99), voter));
+        assert!(!gate.admits(holding(gate.mint, voter, 0), voter));
+    }
+
+    #[test]
+    fn token_gate_rejects_another_wallets_token_account() {
+        let gate = TokenGate {
+            mint: Pubkey::new_unique(),
+            min_balance: 100,
+        };
+        let voter = Pubkey::new_unique();
+        let holder = Pubkey::new_unique();
+        assert!(!gate.admits(holding(gate.mint, holder, 1_000), voter));
+    }
+
+    #[test]
+    fn collection_gate_admits_a_verified_member() {
+        let collection = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let nft = holding(Pubkey::new_unique(), voter, 1);
+        let member = Collection {
+            verified: true,
+            key: collection,
+        };
+        assert!(admits_collection_member(
+            &collection,
+            nft,
+            nft.mint,
+            Some(&member),
+            voter
+        ));
+    }
+
+    #[test]
+    fn collection_gate_rejects_an_unverified_collection() {
+        let collection = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let nft = holding(Pubkey::new_unique(), voter, 1);
+        let unverified = Collection {
+            verified: false,
+            key: collection,
+        };
+        assert!(!admits_collection_member(
+            &collection,
+            nft,
+            nft.mint,
+            Some(&unverified),
+            voter
+        ));
+        assert!(!admits_collection_member(
+            &collection,
+            nft,
+            nft.mint,
+            None,
+            voter
+        ));
+    }
+
+    #[test]
+    fn collection_gate_rejects_another_collection() {
+        let collection = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let nft = holding(Pubkey::new_unique(), voter, 1);
+        let other = Collection {
+            verified: true,
+            key: Pubkey::new_unique(),
+        };
+        assert!(!admits_collection_member(
+            &collection,
+            nft,
+            nft.mint,
+            Some(&other),
+            voter
+        ));
+    }
+
+    #[test]
+    fn collection_gate_rejects_metadata_of_a_different_mint() {
+        let collection = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let nft = holding(Pubkey::new_unique(), voter, 1);
+        let member = Collection {
+            verified: true,
+            key: collection,
+        };
+        let other_mint = Pubkey::new_unique();
+        assert!(!admits_collection_member(
+            &collection,
+            nft,
+            other_mint,
+            Some(&member),
+            voter
+        ));
+    }
+
+    #[test]
+    fn collection_gate_rejects_an_empty_or_foreign_token_account() {
+        let collection = Pubkey::new_unique();
+        let voter = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let member = Collection {
+            verified: true,
+            key: collection,
+        };
+        let empty = holding(mint, voter, 0);
+        let foreign = holding(mint, Pubkey::new_unique(), 1);
+        assert!(!admits_collection_member(
+            &collection,
+            empty,
+            mint,
+            Some(&member),
+            voter
+        ));
+        assert!(!admits_collection_member(
+            &collection,
+            foreign,
+            mint,
+            Some(&member),
+            voter
+        ));
+    }
+
+    #[test]
+    fn dp_continue_probability_matches_e_to_the_minus_epsilon() {
+        // floor(e^-epsilon * 2^16) at the bounds and in the middle of the accepted range
+        assert_eq!(dp_continue_probability(250), 51039);
+        assert_eq!(dp_continue_probability(1_000), 24109);
+        assert_eq!(dp_continue_probability(5_000), 441);
+        assert_eq!(dp_continue_probability(0), 1 << 16);
+    }
+
+    #[test]
+    fn packed_polls_read_the_shared_multi_option_prefix() {
+        assert_eq!(packed_state_len(8), MULTI_OPTION_PREFIX_LEN as u32);
+        assert_eq!(packed_state_len(16), MULTI_OPTION_PREFIX_LEN as u32);
+        assert_eq!(packed_state_len(32), MULTI_OPTION_STATE_LEN as u32);
+        // num_options, invalid and total follow the four counter slots in every layout
+        assert_eq!(MULTI_OPTION_PREFIX_LEN, 4 + 3);
+        assert_eq!(MULTI_OPTION_STATE_LEN, MULTI_OPTION_PREFIX_LEN + 4);
+    }
+
+    #[test]
+    fn poll_outcome_decodes_every_circuit_code() {
+        assert_eq!(PollOutcome::from_code(0), Some(PollOutcome::No));
+        assert_eq!(PollOutcome::from_code(1), Some(PollOutcome::Yes));
+        assert_eq!(PollOutcome::from_code(2), Some(PollOutcome::Tie));
+        assert_eq!(PollOutcome::from_code(3), Some(PollOutcome::QuorumNotMet));
+        assert_eq!(PollOutcome::from_code(4), None);
+    }
+
+    fn receipt(state_version: u64) -> VoterRecord {
+        VoterRecord {
+            bump: 0,
+            poll: Pubkey::new_unique(),
+            voter: Pubkey::new_unique(),
+            has_voted: true,
+            state_version,
+        }
+    }
+
+    #[test]
+    fn settle_vote_drops_a_vote_computed_against_superseded_state() {
+        let mut status = PollStatus::Active;
+        let (mut state_version, mut revealed_slot, mut revealed_at) = (0, 0, 0);
+        let mut lifecycle = Lifecycle {
+            authority: Pubkey::new_unique(),
+            starts_at: 0,
+            ends_at: 0,
+            permissionless_reveal: false,
+            status: &mut status,
+            state_version: &mut state_version,
+            revealed_slot: &mut revealed_slot,
+            revealed_at: &mut revealed_at,
+        };
+        // Both votes were queued against version 0; only the first to call back is applied
+        let mut first = receipt(0);
+        let mut second = receipt(0);
+
+        assert_eq!(lifecycle.settle_vote(&mut first, Some(1)), Some(1));
+        assert!(first.has_voted);
+        assert_eq!(lifecycle.settle_vote(&mut second, Some(2)), None);
+        assert!(!second.has_voted);
+        assert_eq!(*lifecycle.state_version, 1);
+    }
+
+    #[test]
+    fn settle_vote_clears_the_receipt_of_an_aborted_vote() {
+        let mut status = PollStatus::Active;
+        let (mut state_version, mut revealed_slot, mut revealed_at) = (3, 0, 0);
+        let mut lifecycle = Lifecycle {
+            authority: Pubkey::new_unique(),
+            starts_at: 0,
+            ends_at: 0,
+            permissionless_reveal: false,
+            status: &mut status,
+            state_version: &mut state_version,
+            revealed_slot: &mut revealed_slot,
+            revealed_at: &mut revealed_at,
+        };
+        let mut aborted = receipt(3);
+
+        assert_eq!(lifecycle.settle_vote::<u8>(&mut aborted, None), None);
+        assert!(!aborted.has_voted);
+        assert_eq!(*lifecycle.state_version, 3);
+    }
+}
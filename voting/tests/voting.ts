@@ -2,7 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { Voting } from "../target/types/voting";
-import { randomBytes } from "crypto";
+import { createHash, randomBytes } from "crypto";
 import {
   awaitComputationFinalization,
   getArciumEnv,
@@ -25,6 +25,10 @@ import * as fs from "fs";
 import * as os from "os";
 import { expect } from "chai";
 
+const VOTING_WINDOW_SECS = 120;
+// Voting window of the per-feature tests, long enough for a handful of sequential votes
+const FEATURE_WINDOW_SECS = 60;
+
 describe("Voting", () => {
   // Configure the client to use the local cluster.
   anchor.setProvider(anchor.AnchorProvider.env());
@@ -45,15 +49,365 @@ describe("Voting", () => {
   };
 
   const arciumEnv = getArciumEnv();
+  const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
 
-  it("can vote on polls!", async () => {
-    const POLL_IDS = [420, 421, 422];
-    const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+  // Shared by the per-feature tests below, which each cover one poll type or ballot rule
+  let mxePublicKey: Uint8Array;
+  before(async () => {
+    mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider as anchor.AnchorProvider,
+      program.programId
+    );
+  });
+
+  const queueAccounts = (computationOffset: anchor.BN, circuit: string) => ({
+    computationAccount: getComputationAccAddress(
+      program.programId,
+      computationOffset
+    ),
+    clusterAccount: arciumEnv.arciumClusterPubkey,
+    mxeAccount: getMXEAccAddress(program.programId),
+    mempoolAccount: getMempoolAccAddress(program.programId),
+    executingPool: getExecutingPoolAccAddress(program.programId),
+    compDefAccount: getCompDefAccAddress(
+      program.programId,
+      Buffer.from(getCompDefAccOffset(circuit)).readUInt32LE()
+    ),
+  });
 
-    const mxePublicKey = await getMXEPublicKeyWithRetry(
+  // Queues `circuit` through the instruction built by `build` and waits for its callback.
+  const queueAndFinalize = async (
+    circuit: string,
+    signer: anchor.web3.Keypair,
+    build: (computationOffset: anchor.BN) => any,
+    accounts: object
+  ) => {
+    const computationOffset = new anchor.BN(randomBytes(8), "le");
+    await build(computationOffset)
+      .accountsPartial({
+        ...queueAccounts(computationOffset, circuit),
+        payer: signer.publicKey,
+        ...accounts,
+      })
+      .signers([signer])
+      .rpc({ commitment: "confirmed" });
+    return awaitComputationFinalization(
       provider as anchor.AnchorProvider,
+      computationOffset,
+      program.programId,
+      "confirmed"
+    );
+  };
+
+  // Encrypts `values` for the MXE under a fresh voter key.
+  const encryptBallot = (values: bigint[]) => {
+    const privateKey = x25519.utils.randomSecretKey();
+    const cipher = new RescueCipher(
+      x25519.getSharedSecret(privateKey, mxePublicKey)
+    );
+    const nonce = randomBytes(16);
+    return {
+      ciphertexts: cipher.encrypt(values, nonce).map((c) => Array.from(c)),
+      publicKey: Array.from(x25519.getPublicKey(privateKey)),
+      nonce: new anchor.BN(deserializeLE(nonce).toString()),
+    };
+  };
+  type EncryptedBallot = ReturnType<typeof encryptBallot>;
+
+  // Creates a wallet funded by the owner, so each test can cast several ballots.
+  const fundedVoter = async () => {
+    const voter = anchor.web3.Keypair.generate();
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: owner.publicKey,
+          toPubkey: voter.publicKey,
+          lamports: 0.05 * anchor.web3.LAMPORTS_PER_SOL,
+        })
+      )
+    );
+    return voter;
+  };
+
+  // Initializes the computation definitions of `circuits`, skipping ones that already exist.
+  const initCompDefs = async (circuits: string[]) => {
+    for (const circuit of circuits) {
+      const offset = getCompDefAccOffset(circuit);
+      const compDefPDA = PublicKey.findProgramAddressSync(
+        [
+          getArciumAccountBaseSeed("ComputationDefinitionAccount"),
+          program.programId.toBuffer(),
+          offset,
+        ],
+        getArciumProgAddress()
+      )[0];
+      const method = `init_${circuit}_comp_def`.replace(/_(\w)/g, (_, c) =>
+        c.toUpperCase()
+      );
+      try {
+        await (program.methods as any)
+          [method]()
+          .accounts({
+            compDefAccount: compDefPDA,
+            payer: owner.publicKey,
+            mxeAccount: getMXEAccAddress(program.programId),
+          })
+          .signers([owner])
+          .rpc({ commitment: "confirmed" });
+      } catch (e) {
+        if (e.message?.includes("already in use")) {
+          continue;
+        }
+        throw e;
+      }
+      const finalizeTx = await buildFinalizeCompDefTx(
+        provider as anchor.AnchorProvider,
+        Buffer.from(offset).readUInt32LE(),
+        program.programId
+      );
+      const latestBlockhash = await provider.connection.getLatestBlockhash();
+      finalizeTx.recentBlockhash = latestBlockhash.blockhash;
+      finalizeTx.lastValidBlockHeight = latestBlockhash.lastValidBlockHeight;
+      finalizeTx.sign(owner);
+      await provider.sendAndConfirm(finalizeTx);
+    }
+  };
+
+  const TOKEN_PROGRAM_ID = new PublicKey(
+    "TokenkegQfeZyiNwAJbNbGNMQXS6yPjPvDmczQ2Hy7kR"
+  );
+
+  // Creates an SPL mint with 0 decimals whose mint authority is `owner`.
+  const createMint = async () => {
+    const mint = anchor.web3.Keypair.generate();
+    const data = Buffer.alloc(67);
+    data.writeUInt8(20, 0); // InitializeMint2
+    data.writeUInt8(0, 1); // decimals
+    owner.publicKey.toBuffer().copy(data, 2);
+    data.writeUInt8(0, 34); // no freeze authority
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: owner.publicKey,
+          newAccountPubkey: mint.publicKey,
+          lamports:
+            await provider.connection.getMinimumBalanceForRentExemption(82),
+          space: 82,
+          programId: TOKEN_PROGRAM_ID,
+        }),
+        new anchor.web3.TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+          data,
+        })
+      ),
+      [mint]
+    );
+    return mint.publicKey;
+  };
+
+  // Creates a token account of `mint` owned by `wallet` holding `amount` tokens.
+  const mintTokensTo = async (
+    mint: PublicKey,
+    wallet: PublicKey,
+    amount: number
+  ) => {
+    const account = anchor.web3.Keypair.generate();
+    const initData = Buffer.alloc(33);
+    initData.writeUInt8(18, 0); // InitializeAccount3
+    wallet.toBuffer().copy(initData, 1);
+    const mintData = Buffer.alloc(9);
+    mintData.writeUInt8(7, 0); // MintTo
+    mintData.writeBigUInt64LE(BigInt(amount), 1);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: owner.publicKey,
+          newAccountPubkey: account.publicKey,
+          lamports:
+            await provider.connection.getMinimumBalanceForRentExemption(165),
+          space: 165,
+          programId: TOKEN_PROGRAM_ID,
+        }),
+        new anchor.web3.TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [
+            { pubkey: account.publicKey, isSigner: false, isWritable: true },
+            { pubkey: mint, isSigner: false, isWritable: false },
+          ],
+          data: initData,
+        }),
+        new anchor.web3.TransactionInstruction({
+          programId: TOKEN_PROGRAM_ID,
+          keys: [
+            { pubkey: mint, isSigner: false, isWritable: true },
+            { pubkey: account.publicKey, isSigner: false, isWritable: true },
+            { pubkey: owner.publicKey, isSigner: true, isWritable: false },
+          ],
+          data: mintData,
+        })
+      ),
+      [account]
+    );
+    return account.publicKey;
+  };
+
+  type BinaryPollSettings = {
+    minTurnout: number;
+    tokenGate: { mint: PublicKey; minBalance: anchor.BN } | null;
+    weighted: boolean;
+  };
+
+  // Builds the instruction creating a yes/no poll owned by `owner`, for `runPoll`.
+  const createBinaryPoll =
+    (id: number, settings: Partial<BinaryPollSettings> = {}) =>
+    (
+      offset: anchor.BN,
+      nonce: anchor.BN,
+      startsAt: anchor.BN,
+      endsAt: anchor.BN
+    ) => {
+      const s: BinaryPollSettings = {
+        minTurnout: 0,
+        tokenGate: null,
+        weighted: false,
+        ...settings,
+      };
+      return program.methods.createNewPoll(
+        offset,
+        id,
+        `Poll ${id}`,
+        nonce,
+        startsAt,
+        endsAt,
+        false,
+        new anchor.BN(s.minTurnout),
+        null,
+        s.tokenGate,
+        null,
+        null,
+        s.weighted
+      );
+    };
+
+  type MultiOptionPollSettings = {
+    ballotType: object;
+    creditBudget: number;
+    revealMode: object;
+    dpEpsilonMillis: number;
+    minTurnout: number;
+    minBallots: number;
+    allowlistRoot: number[] | null;
+  };
+
+  // Builds the instruction creating a multi-option poll owned by `owner`, for `runPoll`.
+  const createMultiOptionPoll =
+    (
+      id: number,
+      options: string[],
+      settings: Partial<MultiOptionPollSettings> = {}
+    ) =>
+    (
+      offset: anchor.BN,
+      nonce: anchor.BN,
+      startsAt: anchor.BN,
+      endsAt: anchor.BN
+    ) => {
+      const s: MultiOptionPollSettings = {
+        ballotType: { singleChoice: {} },
+        creditBudget: 0,
+        revealMode: { fullCounts: {} },
+        dpEpsilonMillis: 0,
+        minTurnout: 0,
+        minBallots: 0,
+        allowlistRoot: null,
+        ...settings,
+      };
+      return program.methods.createMultiOptionPoll(
+        offset,
+        id,
+        `Poll ${id}`,
+        options,
+        nonce,
+        startsAt,
+        endsAt,
+        false,
+        s.ballotType as any,
+        new anchor.BN(s.creditBudget),
+        s.revealMode as any,
+        s.dpEpsilonMillis,
+        new anchor.BN(s.minTurnout),
+        new anchor.BN(s.minBallots),
+        null,
+        s.allowlistRoot,
+        null,
+        false
+      );
+    };
+
+  const multiOptionPollAddress = (id: number) =>
+    getPollAddress(program.programId, owner.publicKey, id, "multi_poll");
+
+  // Runs an instant-runoff poll to its reveal, casting each ranking from a fresh wallet.
+  const runIrvPoll = async (
+    id: number,
+    options: string[],
+    rankings: number[][]
+  ) => {
+    const voters: anchor.web3.Keypair[] = [];
+    for (const _ of rankings) {
+      voters.push(await fundedVoter());
+    }
+    const pollAcc = getPollAddress(
+      program.programId,
+      owner.publicKey,
+      id,
+      "irv_poll"
+    );
+    const [ballotStore] = PublicKey.findProgramAddressSync(
+      [Buffer.from("irv_ballots"), pollAcc.toBuffer()],
       program.programId
     );
+    await runPoll({
+      circuits: {
+        init: "init_irv_ballot_box",
+        vote: "vote_irv",
+        reveal: "reveal_irv_result",
+      },
+      accounts: { pollAcc, ballotStore },
+      create: (offset, nonce, startsAt, endsAt) =>
+        program.methods.createIrvPoll(
+          offset,
+          id,
+          `Poll ${id}`,
+          options,
+          // The owner keeps the electorate non-empty when no rankings are cast
+          [owner.publicKey, ...voters.map((v) => v.publicKey)],
+          nonce,
+          startsAt,
+          endsAt,
+          false
+        ),
+      // Slots beyond the poll's option count are ignored by the circuit
+      ballots: rankings.map((ranking) =>
+        [0, 1, 2, 3].map((k) => ranking[k] ?? 0)
+      ),
+      voters,
+      vote: (offset, ballot) =>
+        program.methods.voteIrv(
+          offset,
+          id,
+          ballot.ciphertexts,
+          ballot.publicKey,
+          ballot.nonce
+        ),
+      reveal: (offset) => program.methods.revealIrvResult(offset, id),
+    });
+    return program.account.irvPollAccount.fetch(pollAcc);
+  };
+
+  it("can vote on polls!", async () => {
+    const POLL_IDS = [420, 421, 422];
 
     console.log("MXE x25519 pubkey is", mxePublicKey);
 
@@ -123,6 +477,10 @@ describe("Voting", () => {
     const sharedSecret = x25519.getSharedSecret(privateKey, mxePublicKey);
     const cipher = new RescueCipher(sharedSecret);
 
+    // Voting window shared by all polls; reveal is only allowed once it has ended
+    const startsAt = (await getOnChainTime(provider as anchor.AnchorProvider)) - 5;
+    const endsAt = startsAt + VOTING_WINDOW_SECS;
+
     // Create multiple polls
     for (const POLL_ID of POLL_IDS) {
       const pollNonce = randomBytes(16);
@@ -135,7 +493,16 @@ describe("Voting", () => {
           pollComputationOffset,
           POLL_ID,
           `Poll ${POLL_ID}: $SOL to 500?`,
-          new anchor.BN(deserializeLE(pollNonce).toString())
+          new anchor.BN(deserializeLE(pollNonce).toString()),
+          new anchor.BN(startsAt),
+          new anchor.BN(endsAt),
+          false,
+          new anchor.BN(1), // each poll receives exactly one vote
+          null, // simple majority
+          null, // any wallet may vote
+          null, // no allowlist
+          null, // no NFT collection
+          false // one vote per ballot
         )
         .accountsPartial({
           computationAccount: getComputationAccAddress(
@@ -187,7 +554,8 @@ describe("Voting", () => {
           POLL_ID,
           Array.from(ciphertext[0]),
           Array.from(publicKey),
-          new anchor.BN(deserializeLE(nonce).toString())
+          new anchor.BN(deserializeLE(nonce).toString()),
+          [] // poll has no allowlist
         )
         .accountsPartial({
           computationAccount: getComputationAccAddress(
@@ -202,7 +570,7 @@ describe("Voting", () => {
             program.programId,
             Buffer.from(getCompDefAccOffset("vote")).readUInt32LE()
           ),
-          authority: owner.publicKey,
+          pollAcc: getPollAddress(program.programId, owner.publicKey, POLL_ID),
         })
         .rpc({ skipPreflight: true, commitment: "confirmed" });
       console.log(`Queue vote for poll ${POLL_ID} sig is `, queueVoteSig);
@@ -222,10 +590,55 @@ describe("Voting", () => {
       );
     }
 
+    // A second vote from the same wallet must be rejected by the voter record
+    {
+      const POLL_ID = POLL_IDS[0];
+      const nonce = randomBytes(16);
+      const ciphertext = cipher.encrypt([BigInt(true)], nonce);
+      const voteComputationOffset = new anchor.BN(randomBytes(8), "le");
+
+      let rejected = false;
+      try {
+        await program.methods
+          .vote(
+            voteComputationOffset,
+            POLL_ID,
+            Array.from(ciphertext[0]),
+            Array.from(publicKey),
+            new anchor.BN(deserializeLE(nonce).toString()),
+            [] // poll has no allowlist
+          )
+          .accountsPartial({
+            computationAccount: getComputationAccAddress(
+              program.programId,
+              voteComputationOffset
+            ),
+            clusterAccount: arciumEnv.arciumClusterPubkey,
+            mxeAccount: getMXEAccAddress(program.programId),
+            mempoolAccount: getMempoolAccAddress(program.programId),
+            executingPool: getExecutingPoolAccAddress(program.programId),
+            compDefAccount: getCompDefAccAddress(
+              program.programId,
+              Buffer.from(getCompDefAccOffset("vote")).readUInt32LE()
+            ),
+            pollAcc: getPollAddress(program.programId, owner.publicKey, POLL_ID),
+          })
+          .rpc({ commitment: "confirmed" });
+      } catch (e) {
+        expect(e.message).to.include("AlreadyVoted");
+        rejected = true;
+      }
+      expect(rejected).to.equal(true);
+    }
+
+    console.log("Waiting for the voting window to close");
+    await waitForOnChainTime(provider as anchor.AnchorProvider, endsAt);
+
     // Reveal results for each poll
     for (let i = 0; i < POLL_IDS.length; i++) {
       const POLL_ID = POLL_IDS[i];
-      const expectedOutcome = voteOutcomes[i];
+      // Each poll received a single vote, so there are no ties here
+      const expectedOutcome = voteOutcomes[i] ? { yes: {} } : { no: {} };
 
       const revealEventPromise = awaitEvent("revealResultEvent");
 
@@ -247,6 +660,7 @@ describe("Voting", () => {
             program.programId,
             Buffer.from(getCompDefAccOffset("reveal_result")).readUInt32LE()
           ),
+          pollAcc: getPollAddress(program.programId, owner.publicKey, POLL_ID),
         })
         .rpc({ skipPreflight: true, commitment: "confirmed" });
       console.log(`Reveal queue for poll ${POLL_ID} sig is `, revealQueueSig);
@@ -267,10 +681,598 @@ describe("Voting", () => {
         `Decrypted winner for poll ${POLL_ID} is `,
         revealEvent.output
       );
-      expect(revealEvent.output).to.equal(expectedOutcome);
+      expect(revealEvent.output).to.deep.equal(expectedOutcome);
+
+      // The outcome is also persisted on the poll account
+      const pollAcc = await program.account.pollAccount.fetch(
+        getPollAddress(program.programId, owner.publicKey, POLL_ID)
+      );
+      expect(pollAcc.status).to.deep.equal({ revealed: {} });
+      expect(pollAcc.result).to.deep.equal(expectedOutcome);
     }
   });
 
+  it("counts a ranked ballot with duplicate ranks as invalid", async () => {
+    const POLL_ID = 510;
+    const pollAcc = getPollAddress(
+      program.programId,
+      owner.publicKey,
+      POLL_ID,
+      "ranked_poll"
+    );
+    await runPoll({
+      circuits: {
+        init: "init_ranked_vote_stats",
+        vote: "vote_ranked",
+        reveal: "reveal_ranked_result",
+      },
+      accounts: { pollAcc },
+      create: (offset, nonce, startsAt, endsAt) =>
+        program.methods.createRankedPoll(
+          offset,
+          POLL_ID,
+          `Poll ${POLL_ID}`,
+          ["A", "B", "C"],
+          nonce,
+          startsAt,
+          endsAt,
+          false
+        ),
+      // The second ballot ranks nominee 1 twice and must not earn any points
+      ballots: [
+        [0, 1, 2],
+        [1, 1, 2],
+      ],
+      vote: (offset, ballot) =>
+        program.methods.voteRanked(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.ciphertexts[1],
+          ballot.ciphertexts[2],
+          ballot.publicKey,
+          ballot.nonce
+        ),
+      reveal: (offset) => program.methods.revealRankedResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.rankedPollAccount.fetch(pollAcc);
+    expect(poll.scores.slice(0, 3).map((s) => s.toNumber())).to.deep.equal([
+      10, 6, 3,
+    ]);
+    expect(poll.invalidBallots.toNumber()).to.equal(1);
+  });
+
+  it("counts an approval of a nonexistent option as invalid", async () => {
+    const POLL_ID = 511;
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_approval",
+        reveal: "reveal_multi_option_result",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, ["A", "B", "C"], {
+        ballotType: { approval: {} },
+      }),
+      // The second ballot also approves slot 3, which a 3-option poll does not have
+      ballots: [
+        [1, 0, 1, 0],
+        [0, 1, 0, 1],
+      ],
+      vote: (offset, ballot) =>
+        program.methods.voteApproval(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts,
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) =>
+        program.methods.revealMultiOptionResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    expect(
+      poll.optionCounts.slice(0, 3).map((c) => c.toNumber())
+    ).to.deep.equal([1, 0, 1]);
+    expect(poll.invalidBallots.toNumber()).to.equal(1);
+  });
+
+  it("clamps score ratings at 10", async () => {
+    const POLL_ID = 512;
+    const pollAcc = getPollAddress(
+      program.programId,
+      owner.publicKey,
+      POLL_ID,
+      "score_poll"
+    );
+    await runPoll({
+      circuits: {
+        init: "init_score_vote_stats",
+        vote: "vote_score",
+        reveal: "reveal_score_result",
+      },
+      accounts: { pollAcc },
+      create: (offset, nonce, startsAt, endsAt) =>
+        program.methods.createScorePoll(
+          offset,
+          POLL_ID,
+          `Poll ${POLL_ID}`,
+          ["A", "B", "C"],
+          nonce,
+          startsAt,
+          endsAt,
+          false
+        ),
+      // Ratings above 10 count as 10; the unused fourth slot is ignored
+      ballots: [
+        [255, 4, 11, 9],
+        [7, 10, 0, 0],
+      ],
+      vote: (offset, ballot) =>
+        program.methods.voteScore(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts,
+          ballot.publicKey,
+          ballot.nonce
+        ),
+      reveal: (offset) => program.methods.revealScoreResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.scorePollAccount.fetch(pollAcc);
+    expect(poll.scoreTotals.map((t) => t.toNumber())).to.deep.equal([
+      17, 14, 10, 0,
+    ]);
+    expect(poll.voterCount.toNumber()).to.equal(2);
+  });
+
+  it("flags a tie when revealing only the winner", async () => {
+    const POLL_ID = 513;
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_multi_option",
+        reveal: "reveal_multi_option_winner",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, ["A", "B", "C"], {
+        revealMode: { winnerOnly: {} },
+      }),
+      ballots: [[2], [0]],
+      vote: (offset, ballot) =>
+        program.methods.voteMultiOption(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) =>
+        program.methods.revealMultiOptionWinner(offset, POLL_ID),
+    });
+
+    // Options 0 and 2 share the top count; the lowest tied index is reported
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    expect(poll.isTie).to.equal(true);
+    expect(poll.winner).to.equal(0);
+    expect(poll.marginBucket).to.equal(0);
+    expect(poll.quorumMet).to.equal(true);
+    expect(poll.optionCounts.every((c) => c.isZero())).to.equal(true);
+  });
+
+  it("counts abstentions toward turnout but not the majority", async () => {
+    const POLL_ID = 514;
+    const pollAcc = getPollAddress(program.programId, owner.publicKey, POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_vote_stats",
+        vote: "vote_tristate",
+        reveal: "reveal_result",
+      },
+      accounts: { pollAcc },
+      // Only the two abstentions let the single yes vote reach the turnout of 3
+      create: createBinaryPoll(POLL_ID, { minTurnout: 3 }),
+      ballots: [[1], [2], [2]],
+      vote: (offset, ballot) =>
+        program.methods.voteTristate(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) => program.methods.revealResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.pollAccount.fetch(pollAcc);
+    expect(poll.result).to.deep.equal({ yes: {} });
+  });
+
+  it("hides the counts of a poll below its k-anonymity threshold", async () => {
+    const POLL_ID = 515;
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_multi_option",
+        reveal: "reveal_multi_option_result",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, ["A", "B"], { minBallots: 3 }),
+      ballots: [[1], [1]],
+      vote: (offset, ballot) =>
+        program.methods.voteMultiOption(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) =>
+        program.methods.revealMultiOptionResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    expect(poll.sufficientParticipation).to.equal(false);
+    expect(poll.optionCounts.every((c) => c.isZero())).to.equal(true);
+    expect(poll.invalidBallots.toNumber()).to.equal(0);
+  });
+
+  it("counts an over-budget quadratic ballot as invalid", async () => {
+    const POLL_ID = 516;
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_quadratic",
+        reveal: "reveal_multi_option_result",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, ["A", "B", "C"], {
+        ballotType: { quadratic: {} },
+        creditBudget: 9,
+      }),
+      // The last ballot costs 3*3 + 1*1 = 10 credits, one more than the budget
+      ballots: [
+        [3, 0, 0, 0],
+        [2, 2, 1, 0],
+        [3, 1, 0, 0],
+      ],
+      vote: (offset, ballot) =>
+        program.methods.voteQuadratic(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts,
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) =>
+        program.methods.revealMultiOptionResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    expect(
+      poll.optionCounts.slice(0, 3).map((c) => c.toNumber())
+    ).to.deep.equal([5, 2, 1]);
+    expect(poll.invalidBallots.toNumber()).to.equal(1);
+  });
+
+  it("counts an out-of-range option as invalid", async () => {
+    const POLL_ID = 500;
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_multi_option",
+        reveal: "reveal_multi_option_result",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, ["A", "B", "C"]),
+      // Option index 3 does not exist on a 3-option poll
+      ballots: [[1], [3]],
+      vote: (offset, ballot) =>
+        program.methods.voteMultiOption(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) =>
+        program.methods.revealMultiOptionResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    expect(
+      poll.optionCounts.slice(0, 3).map((c) => c.toNumber())
+    ).to.deep.equal([0, 1, 0]);
+    expect(poll.invalidBallots.toNumber()).to.equal(1);
+  });
+
+  it("runs an 8-option poll end to end", async () => {
+    const POLL_ID = 501;
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_multi_option_8",
+        reveal: "reveal_multi_option_8_result",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, [
+        "A",
+        "B",
+        "C",
+        "D",
+        "E",
+        "F",
+        "G",
+        "H",
+      ]),
+      // Options 2 and 6 sit in different lanes of different packed words
+      ballots: [[6], [2], [6]],
+      vote: (offset, ballot) =>
+        program.methods.voteMultiOption8(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) =>
+        program.methods.revealMultiOption8Result(offset, POLL_ID),
+    });
+
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    expect(poll.capacity).to.equal(8);
+    expect(
+      poll.optionCounts.slice(0, 8).map((c) => c.toNumber())
+    ).to.deep.equal([0, 0, 1, 0, 0, 0, 2, 0]);
+    expect(poll.invalidBallots.toNumber()).to.equal(0);
+  });
+
+  it("elects an instant-runoff majority winner in the first round", async () => {
+    const poll = await runIrvPoll(502, ["A", "B", "C"], [
+      [1, 0, 2],
+      [1, 2, 0],
+      [0, 1, 2],
+      // Ranks option 0 twice, so it is counted as invalid and not stored
+      [0, 0, 1],
+    ]);
+    expect(poll.winner).to.equal(1);
+    expect(poll.rounds).to.equal(1);
+    expect(poll.invalidBallots).to.equal(1);
+  });
+
+  it("eliminates the higher index on an instant-runoff tie", async () => {
+    // A three-way tie eliminates option 2, whose voter then prefers option 0
+    const poll = await runIrvPoll(503, ["A", "B", "C"], [
+      [0, 1, 2],
+      [1, 0, 2],
+      [2, 0, 1],
+    ]);
+    expect(poll.winner).to.equal(0);
+    expect(poll.rounds).to.equal(2);
+    expect(poll.invalidBallots).to.equal(0);
+  });
+
+  it("reads only the two option slots of a 2-option instant-runoff poll", async () => {
+    const poll = await runIrvPoll(504, ["A", "B"], [
+      [1, 0],
+      [1, 0],
+      [0, 1],
+    ]);
+    expect(poll.winner).to.equal(1);
+    expect(poll.rounds).to.equal(1);
+    expect(poll.invalidBallots).to.equal(0);
+  });
+
+  it("reveals an empty instant-runoff ballot box", async () => {
+    const poll = await runIrvPoll(505, ["A", "B", "C"], []);
+    expect(poll.rounds).to.equal(0);
+    expect(poll.invalidBallots).to.equal(0);
+    expect(poll.status).to.deep.equal({ revealed: {} });
+  });
+
+  it("only accepts votes from allowlisted wallets", async () => {
+    const POLL_ID = 507;
+    const members = [
+      await fundedVoter(),
+      await fundedVoter(),
+      await fundedVoter(),
+    ];
+    const outsider = await fundedVoter();
+    const allowlist = buildAllowlist(
+      members.map((m) => allowlistLeaf(m.publicKey))
+    );
+    const proofOf = (wallet: PublicKey) =>
+      allowlist.proof(allowlistLeaf(wallet));
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    const voteFor =
+      (proof: number[][]) => (offset: anchor.BN, ballot: EncryptedBallot) =>
+        program.methods.voteMultiOption(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          proof
+        );
+
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_multi_option",
+        reveal: "reveal_multi_option_result",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, ["A", "B"], {
+        allowlistRoot: allowlist.root,
+      }),
+      ballots: [[1]],
+      voters: [members[1]],
+      vote: (offset, ballot, i) =>
+        voteFor(proofOf(members[1 + i].publicKey))(offset, ballot),
+      whileOpen: async () => {
+        // A member's proof does not carry over to another wallet
+        let rejected = false;
+        try {
+          await queueAndFinalize(
+            "vote_multi_option",
+            outsider,
+            (offset) =>
+              voteFor(proofOf(members[0].publicKey))(
+                offset,
+                encryptBallot([BigInt(1)])
+              ),
+            { pollAcc }
+          );
+        } catch (e) {
+          expect(e.message).to.include("NotOnAllowlist");
+          rejected = true;
+        }
+        expect(rejected).to.equal(true);
+      },
+      reveal: (offset) =>
+        program.methods.revealMultiOptionResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    expect(
+      poll.optionCounts.slice(0, 2).map((c) => c.toNumber())
+    ).to.deep.equal([0, 1]);
+  });
+
+  it("reveals noisy counts on a differentially private poll", async () => {
+    const POLL_ID = 508;
+    const pollAcc = multiOptionPollAddress(POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_multi_option_vote_stats",
+        vote: "vote_multi_option",
+        reveal: "reveal_multi_option_result_dp",
+      },
+      accounts: { pollAcc },
+      create: createMultiOptionPoll(POLL_ID, ["A", "B", "C"], {
+        revealMode: { noisyCounts: {} },
+        dpEpsilonMillis: 5000,
+        minTurnout: 3,
+        minBallots: 3,
+      }),
+      ballots: [[0], [2], [2]],
+      vote: (offset, ballot) =>
+        program.methods.voteMultiOption(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) =>
+        program.methods.revealMultiOptionResultDp(offset, POLL_ID),
+    });
+
+    // The noise is random, but each count moves by at most the circuit's cap of 64
+    const poll = await program.account.multiOptionPollAccount.fetch(pollAcc);
+    const exact = [1, 0, 2];
+    poll.optionCounts.slice(0, 3).forEach((c, i) => {
+      expect(Math.abs(c.toNumber() - exact[i])).to.be.at.most(64);
+    });
+    expect(poll.optionCounts[3].toNumber()).to.equal(0);
+    expect(poll.quorumMet).to.equal(true);
+    expect(poll.sufficientParticipation).to.equal(true);
+    expect(poll.status).to.deep.equal({ revealed: {} });
+  });
+
+  it("reveals a tie on an evenly split yes/no poll", async () => {
+    const POLL_ID = 509;
+    const pollAcc = getPollAddress(program.programId, owner.publicKey, POLL_ID);
+    await runPoll({
+      circuits: {
+        init: "init_vote_stats",
+        vote: "vote",
+        reveal: "reveal_result",
+      },
+      accounts: { pollAcc },
+      create: createBinaryPoll(POLL_ID),
+      ballots: [[1], [0]],
+      vote: (offset, ballot) =>
+        program.methods.vote(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) => program.methods.revealResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.pollAccount.fetch(pollAcc);
+    expect(poll.result).to.deep.equal({ tie: {} });
+    expect(poll.status).to.deep.equal({ revealed: {} });
+  });
+
+  it("weighs each vote by the voter's live token balance", async () => {
+    const POLL_ID = 506;
+    const pollAcc = getPollAddress(program.programId, owner.publicKey, POLL_ID);
+    const mint = await createMint();
+    const voters = [
+      await fundedVoter(),
+      await fundedVoter(),
+      await fundedVoter(),
+    ];
+    const balances = [5, 2, 2];
+    const tokenAccounts: PublicKey[] = [];
+    for (const [i, voter] of voters.entries()) {
+      tokenAccounts.push(
+        await mintTokensTo(mint, voter.publicKey, balances[i])
+      );
+    }
+
+    await runPoll({
+      circuits: {
+        init: "init_vote_stats",
+        vote: "vote_weighted",
+        reveal: "reveal_result",
+      },
+      accounts: { pollAcc },
+      create: createBinaryPoll(POLL_ID, {
+        tokenGate: { mint, minBalance: new anchor.BN(1) },
+        weighted: true,
+      }),
+      // One yes vote holding 5 tokens outweighs two no votes holding 2 each
+      ballots: [[1], [0], [0]],
+      voters,
+      voteAccounts: (i) => ({ voterTokenAccount: tokenAccounts[i] }),
+      vote: (offset, ballot) =>
+        program.methods.voteWeighted(
+          offset,
+          POLL_ID,
+          ballot.ciphertexts[0],
+          ballot.publicKey,
+          ballot.nonce,
+          []
+        ),
+      reveal: (offset) => program.methods.revealResult(offset, POLL_ID),
+    });
+
+    const poll = await program.account.pollAccount.fetch(pollAcc);
+    expect(poll.result).to.deep.equal({ yes: {} });
+  });
+
   async function initVoteStatsCompDef(
     program: Program<Voting>,
     owner: anchor.web3.Keypair,
@@ -474,6 +1476,73 @@ describe("Voting", () => {
     }
     return sig;
   }
+
+  // Runs a poll end to end: creates it through the `init` circuit, casts every ballot from a
+  // fresh funded wallet, waits for the voting window to close and queues the reveal.
+  async function runPoll(poll: {
+    circuits: { init: string; vote: string; reveal: string };
+    accounts: object;
+    create: (
+      computationOffset: anchor.BN,
+      nonce: anchor.BN,
+      startsAt: anchor.BN,
+      endsAt: anchor.BN
+    ) => any;
+    ballots: number[][];
+    // Wallets casting the ballots, in order; fresh funded wallets by default
+    voters?: anchor.web3.Keypair[];
+    // Accounts specific to the `index`th ballot, on top of `accounts`
+    voteAccounts?: (index: number) => object;
+    vote: (
+      computationOffset: anchor.BN,
+      ballot: EncryptedBallot,
+      index: number
+    ) => any;
+    // Extra checks to run after the ballots are cast, while the poll is still open
+    whileOpen?: () => Promise<void>;
+    reveal: (computationOffset: anchor.BN) => any;
+  }) {
+    await initCompDefs([
+      poll.circuits.init,
+      poll.circuits.vote,
+      poll.circuits.reveal,
+    ]);
+    const startsAt =
+      (await getOnChainTime(provider as anchor.AnchorProvider)) - 5;
+    const endsAt = startsAt + FEATURE_WINDOW_SECS;
+    await queueAndFinalize(
+      poll.circuits.init,
+      owner,
+      (offset) =>
+        poll.create(
+          offset,
+          new anchor.BN(deserializeLE(randomBytes(16)).toString()),
+          new anchor.BN(startsAt),
+          new anchor.BN(endsAt)
+        ),
+      poll.accounts
+    );
+
+    for (const [i, values] of poll.ballots.entries()) {
+      const voter = poll.voters?.[i] ?? (await fundedVoter());
+      const ballot = encryptBallot(values.map(BigInt));
+      await queueAndFinalize(
+        poll.circuits.vote,
+        voter,
+        (offset) => poll.vote(offset, ballot, i),
+        { ...poll.accounts, ...poll.voteAccounts?.(i) }
+      );
+    }
+    await poll.whileOpen?.();
+
+    await waitForOnChainTime(provider as anchor.AnchorProvider, endsAt);
+    await queueAndFinalize(
+      poll.circuits.reveal,
+      owner,
+      poll.reveal,
+      poll.accounts
+    );
+  }
 });
 
 async function getMXEPublicKeyWithRetry(
@@ -505,6 +1574,77 @@ async function getMXEPublicKeyWithRetry(
   );
 }
 
+function getPollAddress(
+  programId: PublicKey,
+  authority: PublicKey,
+  pollId: number,
+  seed: string = "poll"
+): PublicKey {
+  const idBytes = Buffer.alloc(4);
+  idBytes.writeUInt32LE(pollId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(seed), authority.toBuffer(), idBytes],
+    programId
+  )[0];
+}
+
+async function getOnChainTime(provider: anchor.AnchorProvider): Promise<number> {
+  const slot = await provider.connection.getSlot("confirmed");
+  return await provider.connection.getBlockTime(slot);
+}
+
+async function waitForOnChainTime(
+  provider: anchor.AnchorProvider,
+  timestamp: number
+): Promise<void> {
+  while ((await getOnChainTime(provider)) < timestamp) {
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+  }
+}
+
+function sha256(...parts: Buffer[]): Buffer {
+  return createHash("sha256").update(Buffer.concat(parts)).digest();
+}
+
+// Leaf of `wallet` in a poll allowlist, hashed as in the program's `allowlist` module.
+function allowlistLeaf(wallet: PublicKey): Buffer {
+  return sha256(Buffer.from([0]), wallet.toBuffer());
+}
+
+// Builds an allowlist root over distinct `leaves` and proofs for them, like `AllowlistTree`.
+function buildAllowlist(leaves: Buffer[]) {
+  const nodeHash = (a: Buffer, b: Buffer) =>
+    Buffer.compare(a, b) <= 0
+      ? sha256(Buffer.from([1]), a, b)
+      : sha256(Buffer.from([1]), b, a);
+
+  const layers = [[...leaves].sort(Buffer.compare)];
+  while (layers[layers.length - 1].length > 1) {
+    const layer = layers[layers.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < layer.length; i += 2) {
+      // A node without a sibling is carried up unchanged
+      next.push(
+        i + 1 < layer.length ? nodeHash(layer[i], layer[i + 1]) : layer[i]
+      );
+    }
+    layers.push(next);
+  }
+
+  const proof = (leaf: Buffer) => {
+    let index = layers[0].findIndex((l) => l.equals(leaf));
+    const path: number[][] = [];
+    for (const layer of layers.slice(0, -1)) {
+      if ((index ^ 1) < layer.length) {
+        path.push(Array.from(layer[index ^ 1]));
+      }
+      index = Math.floor(index / 2);
+    }
+    return path;
+  };
+  return { root: Array.from(layers[layers.length - 1][0]), proof };
+}
+
 function readKpJson(path: string): anchor.web3.Keypair {
   const file = fs.readFileSync(path);
   return anchor.web3.Keypair.fromSecretKey(